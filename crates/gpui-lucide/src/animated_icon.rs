@@ -0,0 +1,390 @@
+//! Icon that draws its strokes progressively.
+
+use crate::{
+  Icon, IconColor, IconFit, IconGradient, IconNamed, IconSize, IconTransform, VectorIcon,
+  resolve_geometry,
+};
+use gpui::{
+  Animation, AnimationExt, App, ElementId, Hsla, IntoElement, Radians, RenderOnce, StyleRefinement,
+  Styled, Window, ease_in_out,
+};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// An icon whose strokes are drawn on from start to end, like Lucide's animated variants.
+///
+/// Each path, circle or other element of the icon is drawn in turn, optionally offset
+/// from the previous one by a stagger delay. The animation plays once when the element
+/// is first rendered; give it a new id to play it again.
+///
+/// It wraps an [`Icon`], so it works with any `IconNamed` icon and takes the same
+/// color, size, fit, transform and debug settings; icons outside the built-in set are
+/// loaded from the app's `AssetSource` and parsed on first use. Since gpui's `svg()`
+/// paints an SVG whole, the icon is drawn as a [`VectorIcon`] instead, which strokes
+/// each element as a path and so can trim them to their progress. A gradient is
+/// painted with its first color, since paths are stroked with a single color.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{AnimatedIcon, IconName};
+/// use std::time::Duration;
+///
+/// let check = AnimatedIcon::new("saved-check", IconName::CircleCheck)
+///     .duration(Duration::from_millis(400))
+///     .stagger(Duration::from_millis(150))
+///     .color(gpui::rgb(0x22c55e))
+///     .size_8();
+/// ```
+#[derive(IntoElement)]
+pub struct AnimatedIcon {
+  id: ElementId,
+  icon: Icon,
+  stroke_width: Option<f32>,
+  duration: Duration,
+  stagger: Duration,
+  easing: Rc<dyn Fn(f32) -> f32>,
+  reverse: bool,
+  repeat: bool,
+}
+
+impl AnimatedIcon {
+  /// Creates an animated icon from any type implementing `IconNamed`.
+  pub fn new(id: impl Into<ElementId>, icon: impl IconNamed) -> Self {
+    Self::from_icon(id, Icon::new(icon))
+  }
+
+  /// Creates an animated icon that keeps every setting of `icon`.
+  pub fn from_icon(id: impl Into<ElementId>, icon: Icon) -> Self {
+    Self {
+      id: id.into(),
+      icon,
+      stroke_width: None,
+      duration: Duration::from_millis(600),
+      stagger: Duration::ZERO,
+      easing: Rc::new(ease_in_out),
+      reverse: false,
+      repeat: false,
+    }
+  }
+
+  /// Sets how long each element takes to draw. Defaults to 600ms.
+  pub fn duration(mut self, duration: Duration) -> Self {
+    self.duration = duration;
+    self
+  }
+
+  /// Sets the delay between the start of consecutive elements. Defaults to none.
+  pub fn stagger(mut self, stagger: Duration) -> Self {
+    self.stagger = stagger;
+    self
+  }
+
+  /// Sets the easing function applied to each element's progress.
+  pub fn easing(mut self, easing: impl Fn(f32) -> f32 + 'static) -> Self {
+    self.easing = Rc::new(easing);
+    self
+  }
+
+  /// Erases the strokes instead of drawing them.
+  pub fn reverse(mut self, reverse: bool) -> Self {
+    self.reverse = reverse;
+    self
+  }
+
+  /// Restarts the animation each time it completes.
+  pub fn repeat(mut self) -> Self {
+    self.repeat = true;
+    self
+  }
+
+  /// Sets the icon color, replacing any color token.
+  pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+    self.icon = self.icon.color(color);
+    self
+  }
+
+  /// Sets a semantic icon color, replacing any explicit color.
+  ///
+  /// The color is looked up in the [`IconTheme`](crate::IconTheme) for the window's
+  /// appearance when the icon is drawn.
+  pub fn color_token(mut self, token: IconColor) -> Self {
+    self.icon = self.icon.color_token(token);
+    self
+  }
+
+  /// Sets the icon size using predefined sizes.
+  pub fn with_size(mut self, size: IconSize) -> Self {
+    self.icon = self.icon.with_size(size);
+    self
+  }
+
+  /// Sets how the drawing fills a box whose aspect ratio differs from its viewBox.
+  ///
+  /// The fit is computed from the painted bounds, so it also applies to relative
+  /// sizes.
+  pub fn fit(mut self, fit: IconFit) -> Self {
+    self.icon = self.icon.fit(fit);
+    self
+  }
+
+  /// Overrides the stroke width, in viewBox units.
+  pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+    self.stroke_width = Some(stroke_width);
+    self
+  }
+
+  /// Rotates the icon by the given angle in radians, adding to any earlier rotation.
+  pub fn rotate(mut self, radians: impl Into<Radians>) -> Self {
+    self.icon = self.icon.rotate(radians);
+    self
+  }
+
+  /// Replaces the icon transform.
+  pub fn transform(mut self, transform: IconTransform) -> Self {
+    self.icon = self.icon.transform(transform);
+    self
+  }
+
+  /// Shows or hides the debug overlay for this icon, overriding
+  /// [`set_icon_debug`](crate::set_icon_debug).
+  pub fn debug(mut self, enabled: bool) -> Self {
+    self.icon = self.icon.debug(enabled);
+    self
+  }
+}
+
+impl Styled for AnimatedIcon {
+  fn style(&mut self) -> &mut StyleRefinement {
+    &mut self.icon.custom_style
+  }
+}
+
+impl RenderOnce for AnimatedIcon {
+  fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    let id = self.id;
+    let stroke_width = self.stroke_width;
+    let easing = self.easing;
+    let (duration, stagger, reverse, repeat) =
+      (self.duration, self.stagger, self.reverse, self.repeat);
+
+    self.icon.render_debuggable(cx, move |icon, cx| {
+      let mut icon = vector_from_icon(icon);
+      icon.stroke_width = stroke_width;
+
+      // Resolve once so the element count is known and frames reuse the geometry.
+      let geometry = icon
+        .path()
+        .cloned()
+        .and_then(|path| resolve_geometry(&path, cx));
+      if let Some(geometry) = geometry {
+        icon = icon.geometry(geometry);
+      }
+      let timeline = DrawTimeline {
+        duration,
+        stagger,
+        count: icon.element_count().unwrap_or(1),
+        reverse,
+      };
+
+      let mut animation = Animation::new(timeline.total());
+      if repeat {
+        animation = animation.repeat();
+      }
+
+      icon
+        .with_animation(id, animation, move |mut icon, delta| {
+          icon.progress = Some(timeline.progress(delta, easing.as_ref()));
+          icon
+        })
+        .into_any_element()
+    })
+  }
+}
+
+/// Converts an `Icon` into the vector form that can be drawn progressively.
+fn vector_from_icon(icon: Icon) -> VectorIcon {
+  let mut vector = VectorIcon::from_path(icon.path);
+  vector.color = icon.color;
+  vector.color_token = icon.color_token;
+  vector.contrast = icon.contrast;
+  if let Some(color) = icon.gradient.as_ref().and_then(IconGradient::first_color) {
+    vector.color = Some(color);
+    vector.color_token = None;
+  }
+  vector.size = icon.size;
+  vector.fit = icon.fit;
+  vector.transform = icon.transform;
+  vector.custom_style = icon.custom_style;
+  vector
+}
+
+/// Schedules the draw progress of each element over the whole animation.
+#[derive(Debug, Clone, Copy)]
+struct DrawTimeline {
+  duration: Duration,
+  stagger: Duration,
+  count: usize,
+  reverse: bool,
+}
+
+impl DrawTimeline {
+  /// Total length of the animation, from the first element starting to the last one
+  /// finishing.
+  fn total(&self) -> Duration {
+    let total = self.duration + self.stagger * self.count.saturating_sub(1) as u32;
+    total.max(Duration::from_millis(1))
+  }
+
+  /// Returns the eased progress of every element at `delta` of the total animation.
+  fn progress(&self, delta: f32, easing: &dyn Fn(f32) -> f32) -> Arc<[f32]> {
+    let elapsed = self.total().as_secs_f32() * delta;
+    let duration = self.duration.as_secs_f32();
+    let stagger = self.stagger.as_secs_f32();
+
+    (0..self.count)
+      .map(|index| {
+        let start = stagger * index as f32;
+        let local = if duration > 0.0 {
+          ((elapsed - start) / duration).clamp(0.0, 1.0)
+        } else if elapsed >= start {
+          1.0
+        } else {
+          0.0
+        };
+        let progress = easing(local);
+        if self.reverse {
+          1.0 - progress
+        } else {
+          progress
+        }
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::IconName;
+
+  fn linear(t: f32) -> f32 {
+    t
+  }
+
+  #[test]
+  fn test_timeline_total_includes_stagger() {
+    let timeline = DrawTimeline {
+      duration: Duration::from_millis(400),
+      stagger: Duration::from_millis(100),
+      count: 3,
+      reverse: false,
+    };
+    assert_eq!(timeline.total(), Duration::from_millis(600));
+  }
+
+  #[test]
+  fn test_timeline_staggers_elements() {
+    let timeline = DrawTimeline {
+      duration: Duration::from_millis(400),
+      stagger: Duration::from_millis(200),
+      count: 2,
+      reverse: false,
+    };
+
+    // 300ms into a 600ms animation.
+    let progress = timeline.progress(0.5, &linear);
+    assert_eq!(progress.len(), 2);
+    assert!((progress[0] - 0.75).abs() < 1e-5);
+    assert!((progress[1] - 0.25).abs() < 1e-5);
+
+    assert!(timeline.progress(0.0, &linear).iter().all(|p| *p == 0.0));
+    assert!(timeline.progress(1.0, &linear).iter().all(|p| *p == 1.0));
+  }
+
+  #[test]
+  fn test_timeline_reverse_erases() {
+    let timeline = DrawTimeline {
+      duration: Duration::from_millis(400),
+      stagger: Duration::ZERO,
+      count: 1,
+      reverse: true,
+    };
+
+    assert_eq!(&*timeline.progress(0.0, &linear), &[1.0]);
+    assert_eq!(&*timeline.progress(1.0, &linear), &[0.0]);
+  }
+
+  #[test]
+  fn test_timeline_applies_easing() {
+    let timeline = DrawTimeline {
+      duration: Duration::from_millis(100),
+      stagger: Duration::ZERO,
+      count: 1,
+      reverse: false,
+    };
+
+    let progress = timeline.progress(0.5, &|t| t * t);
+    assert!((progress[0] - 0.25).abs() < 1e-5);
+  }
+
+  #[test]
+  fn test_from_icon_keeps_icon_options() {
+    let icon = Icon::new(IconName::Check)
+      .color(gpui::rgb(0x22c55e))
+      .with_size(IconSize::Large)
+      .fit(IconFit::Cover);
+    let vector = vector_from_icon(AnimatedIcon::from_icon("check", icon).icon);
+
+    assert_eq!(vector.color, Some(gpui::rgb(0x22c55e).into()));
+    assert_eq!(vector.size, Some(IconSize::Large));
+    assert_eq!(vector.fit, Some(IconFit::Cover));
+    assert_eq!(
+      vector.path().map(ToString::to_string),
+      Some(IconName::Check.path().to_string())
+    );
+  }
+
+  #[test]
+  fn test_builders_match_icon() {
+    let animated = AnimatedIcon::new("check", IconName::Check)
+      .color(gpui::rgb(0xffffff))
+      .color_token(IconColor::Success)
+      .debug(true);
+
+    assert_eq!(animated.icon.color, None);
+    assert_eq!(animated.icon.color_token, Some(IconColor::Success));
+    assert_eq!(animated.icon.debug, Some(true));
+  }
+
+  #[test]
+  fn test_gradient_is_painted_with_its_first_color() {
+    let icon = Icon::new(IconName::Check)
+      .color(gpui::rgb(0xffffff))
+      .gradient(IconGradient::angle(
+        90.,
+        gpui::linear_color_stop(gpui::rgb(0x48dbfb), 0.),
+        gpui::linear_color_stop(gpui::rgb(0x5f27cd), 1.),
+      ));
+    let vector = vector_from_icon(icon);
+
+    assert_eq!(vector.color, Some(gpui::rgb(0x48dbfb).into()));
+  }
+
+  #[test]
+  fn test_from_icon_keeps_color_token_and_contrast() {
+    let background: Hsla = gpui::rgb(0xfeca57).into();
+    let icon = Icon::new(IconName::Check)
+      .color_token(IconColor::Success)
+      .auto_contrast(background);
+    let vector = vector_from_icon(icon);
+
+    assert_eq!(vector.color, None);
+    assert_eq!(vector.color_token, Some(IconColor::Success));
+    assert_eq!(
+      vector.contrast,
+      Some((background, crate::CONTRAST_GRAPHICS))
+    );
+  }
+}
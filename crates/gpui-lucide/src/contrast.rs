@@ -0,0 +1,161 @@
+//! WCAG contrast between icon and background colors.
+
+use gpui::{Hsla, Rgba, black, white};
+
+/// Contrast WCAG requires for icons and other graphical objects (level AA, 1.4.11).
+pub const CONTRAST_GRAPHICS: f32 = 3.0;
+
+/// Contrast WCAG requires for normal text (level AA, 1.4.3).
+pub const CONTRAST_TEXT: f32 = 4.5;
+
+/// Steps of the lightness search in [`contrasting_color`].
+const SEARCH_STEPS: usize = 16;
+
+/// Returns the WCAG contrast ratio between two colors, from 1 to 21.
+///
+/// A translucent `foreground` is composited over `background` first; `background` is
+/// treated as opaque.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{CONTRAST_GRAPHICS, contrast_ratio};
+///
+/// let ratio = contrast_ratio(gpui::white(), gpui::rgb(0xfeca57).into());
+/// let readable = ratio >= CONTRAST_GRAPHICS;
+/// ```
+pub fn contrast_ratio(foreground: Hsla, background: Hsla) -> f32 {
+  let background = Rgba::from(background);
+  let foreground = composite(Rgba::from(foreground), background);
+  let (lighter, darker) = {
+    let a = relative_luminance(foreground);
+    let b = relative_luminance(background);
+    if a > b { (a, b) } else { (b, a) }
+  };
+  (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Returns a color that reaches `target` contrast against `background`.
+///
+/// `preferred` is kept when it already contrasts enough. Otherwise its lightness is
+/// moved towards black or white, whichever contrasts more with `background`, just far
+/// enough to reach `target`, so the hue survives where possible. Without a preferred
+/// color, or when no lightness reaches `target`, black or white is returned.
+pub fn contrasting_color(preferred: Option<Hsla>, background: Hsla, target: f32) -> Hsla {
+  let toward_black = contrast_ratio(black(), background) >= contrast_ratio(white(), background);
+  let Some(color) = preferred else {
+    return if toward_black { black() } else { white() };
+  };
+  if contrast_ratio(color, background) >= target {
+    return color;
+  }
+
+  let extreme = Hsla {
+    l: if toward_black { 0.0 } else { 1.0 },
+    ..color
+  };
+  if contrast_ratio(extreme, background) < target {
+    return extreme;
+  }
+
+  // Contrast grows monotonically from `color.l` to `extreme.l` on this side of the
+  // background, so bisect for the lightness closest to the original.
+  let (mut failing, mut passing) = (color.l, extreme.l);
+  for _ in 0..SEARCH_STEPS {
+    let l = (failing + passing) / 2.0;
+    if contrast_ratio(Hsla { l, ..color }, background) >= target {
+      passing = l;
+    } else {
+      failing = l;
+    }
+  }
+  Hsla {
+    l: passing,
+    ..color
+  }
+}
+
+/// Returns the WCAG relative luminance of an opaque color.
+fn relative_luminance(color: Rgba) -> f32 {
+  let channel = |c: f32| {
+    if c <= 0.04045 {
+      c / 12.92
+    } else {
+      ((c + 0.055) / 1.055).powf(2.4)
+    }
+  };
+  0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b)
+}
+
+/// Blends a translucent color over an opaque one.
+fn composite(foreground: Rgba, background: Rgba) -> Rgba {
+  let a = foreground.a;
+  Rgba {
+    r: foreground.r * a + background.r * (1.0 - a),
+    g: foreground.g * a + background.g * (1.0 - a),
+    b: foreground.b * a + background.b * (1.0 - a),
+    a: 1.0,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use gpui::rgb;
+
+  fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.01, "{} != {}", a, b);
+  }
+
+  #[test]
+  fn test_contrast_ratio() {
+    assert_close(contrast_ratio(black(), white()), 21.0);
+    assert_close(contrast_ratio(white(), black()), 21.0);
+    assert_close(contrast_ratio(white(), white()), 1.0);
+    // #767676 is the lightest gray with 4.5:1 on white.
+    assert_close(contrast_ratio(rgb(0x767676).into(), white()), 4.54);
+  }
+
+  #[test]
+  fn test_contrast_ratio_composites_translucent_colors() {
+    let faded = Hsla { a: 0.0, ..black() };
+    assert_close(contrast_ratio(faded, white()), 1.0);
+  }
+
+  #[test]
+  fn test_contrasting_color_picks_black_or_white() {
+    let yellow: Hsla = rgb(0xfeca57).into();
+    let purple: Hsla = rgb(0x5f27cd).into();
+    assert_eq!(contrasting_color(None, yellow, CONTRAST_TEXT), black());
+    assert_eq!(contrasting_color(None, purple, CONTRAST_TEXT), white());
+  }
+
+  #[test]
+  fn test_contrasting_color_keeps_readable_colors() {
+    let red: Hsla = rgb(0xb91c1c).into();
+    assert_eq!(
+      contrasting_color(Some(red), white(), CONTRAST_GRAPHICS),
+      red
+    );
+  }
+
+  #[test]
+  fn test_contrasting_color_adjusts_lightness() {
+    let background: Hsla = rgb(0xfeca57).into();
+    let color: Hsla = rgb(0xff6b6b).into();
+    let adjusted = contrasting_color(Some(color), background, CONTRAST_GRAPHICS);
+
+    assert!(adjusted.l < color.l);
+    assert_eq!(adjusted.h, color.h);
+    let ratio = contrast_ratio(adjusted, background);
+    assert!(ratio >= CONTRAST_GRAPHICS);
+    assert!(ratio < CONTRAST_GRAPHICS + 0.1);
+  }
+
+  #[test]
+  fn test_contrasting_color_falls_back_to_the_extreme() {
+    let gray: Hsla = rgb(0x777777).into();
+    let adjusted = contrasting_color(Some(gray), gray, 21.0);
+    assert!(adjusted.l == 0.0 || adjusted.l == 1.0);
+  }
+}
@@ -0,0 +1,1096 @@
+//! Icons for files, directories, MIME types and language ids.
+//!
+//! [`icon_for_path`], [`icon_for_directory`], [`icon_for_mime_type`] and
+//! [`icon_for_language`] pick an icon from the built-in [`FileIcons`] table. Apps that
+//! want their own mapping build a `FileIcons`, adjust it in code or from a config file,
+//! and query it instead.
+//!
+//! ```rust,ignore
+//! use gpui_lucide::file_icons::{icon_for_directory, icon_for_mime_type, icon_for_path};
+//!
+//! let icon = icon_for_path(Path::new("Cargo.toml"));
+//! let folder = icon_for_directory(Path::new("src"), true);
+//! let dropped = icon_for_mime_type("image/png");
+//! ```
+
+use crate::IconName;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Icons of a directory while collapsed and while expanded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DirectoryIcons {
+  /// Icon while the directory is collapsed.
+  pub closed: IconName,
+  /// Icon while the directory is expanded.
+  pub open: IconName,
+}
+
+impl DirectoryIcons {
+  /// Uses the same icon in both states.
+  pub fn same(icon: IconName) -> Self {
+    Self {
+      closed: icon,
+      open: icon,
+    }
+  }
+}
+
+/// Mapping from file names, extensions, directory names, MIME types and language ids
+/// to icons.
+///
+/// Files are matched by their full name first, such as `Cargo.toml` or `.gitignore`,
+/// then by extension, longest first so `archive.tar.gz` uses `tar.gz` before `gz`.
+/// Directories are matched by name. MIME types fall back from `image/svg+xml` to the
+/// structured suffix `image/xml`, then to the wildcard `image/*`. Language ids that are
+/// not mapped are tried as extensions. All matching ignores case.
+///
+/// Config files extend or override the table. In TOML:
+///
+/// ```toml
+/// [extensions]
+/// rs = "file-code"
+/// "d.ts" = "file-type"
+///
+/// [file-names]
+/// "Justfile" = "hammer"
+///
+/// [directories]
+/// ".git" = "folder-git"
+/// src = { closed = "folder-code", open = "folder-open" }
+///
+/// [mime-types]
+/// "application/pdf" = "file-text"
+/// "image/*" = "image"
+///
+/// [languages]
+/// rust = "file-code"
+/// ```
+///
+/// JSON files use the same structure.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::file_icons::FileIcons;
+///
+/// let mut icons = FileIcons::new();
+/// icons.load("file-icons.toml")?;
+/// let icon = icons.icon_for_file(Path::new("src/main.rs"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileIcons {
+  extensions: HashMap<String, IconName>,
+  file_names: HashMap<String, IconName>,
+  directories: HashMap<String, DirectoryIcons>,
+  mime_types: HashMap<String, IconName>,
+  languages: HashMap<String, IconName>,
+  file: IconName,
+  directory: DirectoryIcons,
+}
+
+impl Default for FileIcons {
+  fn default() -> Self {
+    let mut icons = Self::empty();
+    for (extensions, icon) in EXTENSIONS {
+      for extension in *extensions {
+        icons.set_extension(extension, *icon);
+      }
+    }
+    for (names, icon) in FILE_NAMES {
+      for name in *names {
+        icons.set_file_name(name, *icon);
+      }
+    }
+    for (names, icon) in DIRECTORIES {
+      for name in *names {
+        icons.set_directory(name, DirectoryIcons::same(*icon));
+      }
+    }
+    for (mime_types, icon) in MIME_TYPES {
+      for mime_type in *mime_types {
+        icons.set_mime_type(mime_type, *icon);
+      }
+    }
+    for (languages, icon) in LANGUAGES {
+      for language in *languages {
+        icons.set_language(language, *icon);
+      }
+    }
+    icons
+  }
+}
+
+impl FileIcons {
+  /// Creates the built-in table.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Creates a table that maps every file to `File` and every directory to `Folder`.
+  pub fn empty() -> Self {
+    Self {
+      extensions: HashMap::new(),
+      file_names: HashMap::new(),
+      directories: HashMap::new(),
+      mime_types: HashMap::new(),
+      languages: HashMap::new(),
+      file: IconName::File,
+      directory: DirectoryIcons {
+        closed: IconName::Folder,
+        open: IconName::FolderOpen,
+      },
+    }
+  }
+
+  /// Maps an extension, without the leading dot, to an icon.
+  pub fn set_extension(&mut self, extension: &str, icon: IconName) {
+    let extension = extension.trim_start_matches('.').to_lowercase();
+    self.extensions.insert(extension, icon);
+  }
+
+  /// Maps an exact file name to an icon.
+  pub fn set_file_name(&mut self, name: &str, icon: IconName) {
+    self.file_names.insert(name.to_lowercase(), icon);
+  }
+
+  /// Maps a directory name to its icons.
+  pub fn set_directory(&mut self, name: &str, icons: DirectoryIcons) {
+    self.directories.insert(name.to_lowercase(), icons);
+  }
+
+  /// Maps a MIME type to an icon. Use `type/*` for a whole top-level type.
+  pub fn set_mime_type(&mut self, mime_type: &str, icon: IconName) {
+    self.mime_types.insert(mime_type.to_lowercase(), icon);
+  }
+
+  /// Maps a language id, such as `rust` or `typescriptreact`, to an icon.
+  pub fn set_language(&mut self, language: &str, icon: IconName) {
+    self.languages.insert(language.to_lowercase(), icon);
+  }
+
+  /// Sets the icon of files that match nothing. Defaults to `File`.
+  pub fn set_default_file(&mut self, icon: IconName) {
+    self.file = icon;
+  }
+
+  /// Sets the icons of directories that match nothing. Defaults to `Folder` and
+  /// `FolderOpen`.
+  pub fn set_default_directory(&mut self, icons: DirectoryIcons) {
+    self.directory = icons;
+  }
+
+  /// Returns the icon for the file at `path`.
+  pub fn icon_for_file(&self, path: &Path) -> IconName {
+    let Some(name) = file_name(path) else {
+      return self.file;
+    };
+    if let Some(icon) = self.file_names.get(&name) {
+      return *icon;
+    }
+
+    // Skip the first character so dotfiles like `.bashrc` have no extension.
+    name
+      .char_indices()
+      .skip(1)
+      .filter(|(_, c)| *c == '.')
+      .find_map(|(index, _)| self.extensions.get(&name[index + 1..]))
+      .copied()
+      .unwrap_or(self.file)
+  }
+
+  /// Returns the icon for the directory at `path`, expanded or not.
+  pub fn icon_for_directory(&self, path: &Path, open: bool) -> IconName {
+    let icons = file_name(path)
+      .and_then(|name| self.directories.get(&name))
+      .unwrap_or(&self.directory);
+    if open { icons.open } else { icons.closed }
+  }
+
+  /// Returns the icon for a MIME type such as `image/png` or `text/x-rust;
+  /// charset=utf-8`.
+  pub fn icon_for_mime_type(&self, mime_type: &str) -> IconName {
+    let essence = mime_type.split(';').next().unwrap_or_default();
+    let essence = essence.trim().to_lowercase();
+    if let Some(icon) = self.mime_types.get(&essence) {
+      return *icon;
+    }
+    let Some((kind, subtype)) = essence.split_once('/') else {
+      return self.file;
+    };
+
+    let suffix = subtype
+      .rsplit_once('+')
+      .and_then(|(_, suffix)| self.mime_types.get(&format!("{}/{}", kind, suffix)));
+    suffix
+      .or_else(|| self.mime_types.get(&format!("{}/*", kind)))
+      .copied()
+      .unwrap_or(self.file)
+  }
+
+  /// Returns the icon for a language id, falling back to treating it as an extension.
+  pub fn icon_for_language(&self, language: &str) -> IconName {
+    let language = language.trim().to_lowercase();
+    self
+      .languages
+      .get(&language)
+      .or_else(|| self.extensions.get(&language))
+      .copied()
+      .unwrap_or(self.file)
+  }
+
+  /// Adds the mappings of a TOML config, replacing existing ones.
+  pub fn extend_from_toml(&mut self, source: &str) -> Result<(), FileIconsError> {
+    let config: Config = toml::from_str(source).map_err(FileIconsError::Toml)?;
+    self.apply(config)
+  }
+
+  /// Adds the mappings of a JSON config, replacing existing ones.
+  pub fn extend_from_json(&mut self, source: &str) -> Result<(), FileIconsError> {
+    let config: Config = serde_json::from_str(source).map_err(FileIconsError::Json)?;
+    self.apply(config)
+  }
+
+  /// Adds the mappings of a `.toml` or `.json` config file, replacing existing ones.
+  pub fn load(&mut self, path: impl AsRef<Path>) -> Result<(), FileIconsError> {
+    let path = path.as_ref();
+    let extension = path.extension().and_then(|extension| extension.to_str());
+    let read = || std::fs::read_to_string(path).map_err(FileIconsError::Io);
+    match extension {
+      Some("toml") => self.extend_from_toml(&read()?),
+      Some("json") => self.extend_from_json(&read()?),
+      _ => Err(FileIconsError::UnsupportedFormat(path.to_path_buf())),
+    }
+  }
+
+  /// Applies a parsed config. Every icon name is checked before anything changes.
+  fn apply(&mut self, config: Config) -> Result<(), FileIconsError> {
+    let extensions = parse_icons(config.extensions)?;
+    let file_names = parse_icons(config.file_names)?;
+    let mime_types = parse_icons(config.mime_types)?;
+    let languages = parse_icons(config.languages)?;
+    let directories = config
+      .directories
+      .into_iter()
+      .map(|(name, icons)| {
+        let icons = match icons {
+          ConfigDirectory::Same(icon) => DirectoryIcons::same(parse_icon(icon)?),
+          ConfigDirectory::States { closed, open } => DirectoryIcons {
+            closed: parse_icon(closed)?,
+            open: parse_icon(open)?,
+          },
+        };
+        Ok((name, icons))
+      })
+      .collect::<Result<Vec<_>, FileIconsError>>()?;
+
+    for (extension, icon) in extensions {
+      self.set_extension(&extension, icon);
+    }
+    for (name, icon) in file_names {
+      self.set_file_name(&name, icon);
+    }
+    for (name, icons) in directories {
+      self.set_directory(&name, icons);
+    }
+    for (mime_type, icon) in mime_types {
+      self.set_mime_type(&mime_type, icon);
+    }
+    for (language, icon) in languages {
+      self.set_language(&language, icon);
+    }
+    Ok(())
+  }
+}
+
+/// Returns the icon for the file at `path` from the built-in table.
+pub fn icon_for_path(path: &Path) -> IconName {
+  default_icons().icon_for_file(path)
+}
+
+/// Returns the icon for the directory at `path` from the built-in table.
+pub fn icon_for_directory(path: &Path, open: bool) -> IconName {
+  default_icons().icon_for_directory(path, open)
+}
+
+/// Returns the icon for a MIME type from the built-in table.
+pub fn icon_for_mime_type(mime_type: &str) -> IconName {
+  default_icons().icon_for_mime_type(mime_type)
+}
+
+/// Returns the icon for a language id from the built-in table.
+pub fn icon_for_language(language: &str) -> IconName {
+  default_icons().icon_for_language(language)
+}
+
+fn default_icons() -> &'static FileIcons {
+  static ICONS: OnceLock<FileIcons> = OnceLock::new();
+  ICONS.get_or_init(FileIcons::new)
+}
+
+fn file_name(path: &Path) -> Option<String> {
+  path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .map(str::to_lowercase)
+}
+
+/// Config file layout, see [`FileIcons`].
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Config {
+  #[serde(default)]
+  extensions: BTreeMap<String, String>,
+  #[serde(default)]
+  file_names: BTreeMap<String, String>,
+  #[serde(default)]
+  directories: BTreeMap<String, ConfigDirectory>,
+  #[serde(default)]
+  mime_types: BTreeMap<String, String>,
+  #[serde(default)]
+  languages: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ConfigDirectory {
+  Same(String),
+  States { closed: String, open: String },
+}
+
+fn parse_icon(name: String) -> Result<IconName, FileIconsError> {
+  IconName::from_name(&name).ok_or(FileIconsError::UnknownIcon(name))
+}
+
+fn parse_icons(
+  entries: BTreeMap<String, String>,
+) -> Result<Vec<(String, IconName)>, FileIconsError> {
+  entries
+    .into_iter()
+    .map(|(key, name)| Ok((key, parse_icon(name)?)))
+    .collect()
+}
+
+/// Error returned when a file icon config cannot be loaded.
+#[derive(Debug)]
+pub enum FileIconsError {
+  /// The file could not be read.
+  Io(io::Error),
+  /// The file is not valid TOML.
+  Toml(toml::de::Error),
+  /// The file is not valid JSON.
+  Json(serde_json::Error),
+  /// A mapping names an icon that does not exist.
+  UnknownIcon(String),
+  /// The file extension is neither `.toml` nor `.json`.
+  UnsupportedFormat(PathBuf),
+}
+
+impl fmt::Display for FileIconsError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Io(error) => write!(f, "failed to read file icon config: {}", error),
+      Self::Toml(error) => write!(f, "invalid TOML file icon config: {}", error),
+      Self::Json(error) => write!(f, "invalid JSON file icon config: {}", error),
+      Self::UnknownIcon(name) => write!(f, "unknown icon name in file icon config: `{}`", name),
+      Self::UnsupportedFormat(path) => write!(
+        f,
+        "unsupported file icon config format: {}, expected .toml or .json",
+        path.display()
+      ),
+    }
+  }
+}
+
+impl std::error::Error for FileIconsError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Self::Io(error) => Some(error),
+      Self::Toml(error) => Some(error),
+      Self::Json(error) => Some(error),
+      Self::UnknownIcon(_) | Self::UnsupportedFormat(_) => None,
+    }
+  }
+}
+
+const EXTENSIONS: &[(&[&str], IconName)] = &[
+  (
+    &[
+      "rs", "c", "h", "cc", "cpp", "cxx", "hpp", "hh", "m", "mm", "go", "py", "pyi", "rb", "java",
+      "kt", "kts", "swift", "js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx", "php", "cs",
+      "fs", "lua", "zig", "ex", "exs", "erl", "hs", "ml", "scala", "clj", "dart", "r", "jl", "nim",
+      "vue", "svelte", "astro", "html", "htm", "xml", "css", "scss", "sass", "less", "graphql",
+      "proto", "wgsl", "glsl", "hlsl", "metal",
+    ],
+    IconName::FileCode,
+  ),
+  (&["d.ts", "d.mts", "d.cts"], IconName::FileType),
+  (
+    &["json", "jsonc", "json5", "jsonl", "ndjson"],
+    IconName::FileBraces,
+  ),
+  (
+    &[
+      "toml",
+      "yaml",
+      "yml",
+      "ini",
+      "cfg",
+      "conf",
+      "properties",
+      "plist",
+    ],
+    IconName::FileCog,
+  ),
+  (
+    &[
+      "md", "markdown", "mdx", "txt", "rst", "adoc", "org", "tex", "rtf", "pdf", "doc", "docx",
+      "odt",
+    ],
+    IconName::FileText,
+  ),
+  (
+    &["csv", "tsv", "xls", "xlsx", "ods", "numbers"],
+    IconName::FileSpreadsheet,
+  ),
+  (
+    &[
+      "png", "jpg", "jpeg", "gif", "webp", "bmp", "ico", "icns", "tif", "tiff", "avif", "heic",
+      "svg", "psd",
+    ],
+    IconName::FileImage,
+  ),
+  (
+    &[
+      "mp3", "wav", "flac", "ogg", "oga", "m4a", "aac", "opus", "aiff", "mid", "midi",
+    ],
+    IconName::FileMusic,
+  ),
+  (
+    &["mp4", "m4v", "mov", "mkv", "avi", "webm", "wmv", "flv"],
+    IconName::FileVideoCamera,
+  ),
+  (
+    &[
+      "zip", "tar", "gz", "tgz", "bz2", "xz", "zst", "7z", "rar", "tar.gz", "tar.bz2", "tar.xz",
+      "tar.zst", "jar", "deb", "rpm", "dmg", "iso",
+    ],
+    IconName::FileArchive,
+  ),
+  (
+    &["sh", "bash", "zsh", "fish", "nu", "ps1", "bat", "cmd"],
+    IconName::FileTerminal,
+  ),
+  (&["lock"], IconName::FileLock),
+  (
+    &[
+      "pem", "key", "crt", "cer", "der", "p12", "pfx", "pub", "gpg", "asc",
+    ],
+    IconName::FileKey,
+  ),
+  (&["diff", "patch"], IconName::FileDiff),
+  (&["ttf", "otf", "woff", "woff2"], IconName::FileType),
+  (&["sql", "db", "sqlite", "sqlite3"], IconName::Database),
+  (
+    &["exe", "dll", "so", "dylib", "wasm", "bin", "o", "a", "lib"],
+    IconName::FileBox,
+  ),
+  (&["log"], IconName::FileClock),
+];
+
+const FILE_NAMES: &[(&[&str], IconName)] = &[
+  (
+    &[
+      "cargo.toml",
+      "package.json",
+      "pyproject.toml",
+      "go.mod",
+      "gemfile",
+      "composer.json",
+    ],
+    IconName::Package,
+  ),
+  (
+    &[
+      "cargo.lock",
+      "package-lock.json",
+      "yarn.lock",
+      "pnpm-lock.yaml",
+      "bun.lockb",
+      "go.sum",
+      "gemfile.lock",
+      "poetry.lock",
+      "composer.lock",
+    ],
+    IconName::FileLock,
+  ),
+  (
+    &[
+      "dockerfile",
+      "containerfile",
+      "docker-compose.yml",
+      "docker-compose.yaml",
+      "compose.yml",
+      "compose.yaml",
+      ".dockerignore",
+    ],
+    IconName::Container,
+  ),
+  (
+    &[
+      "makefile",
+      "gnumakefile",
+      "cmakelists.txt",
+      "justfile",
+      "build.rs",
+      "build.gradle",
+      "rakefile",
+    ],
+    IconName::Hammer,
+  ),
+  (
+    &[".gitignore", ".gitattributes", ".gitmodules", ".gitkeep"],
+    IconName::GitBranch,
+  ),
+  (
+    &[
+      "readme",
+      "readme.md",
+      "readme.txt",
+      "changelog.md",
+      "contributing.md",
+    ],
+    IconName::BookOpen,
+  ),
+  (
+    &[
+      "license",
+      "license.md",
+      "license.txt",
+      "license-mit",
+      "license-apache",
+      "copying",
+    ],
+    IconName::Scale,
+  ),
+  (
+    &[
+      ".env",
+      ".env.local",
+      ".env.development",
+      ".env.production",
+      ".npmrc",
+    ],
+    IconName::FileKey,
+  ),
+  (
+    &[
+      ".editorconfig",
+      ".prettierrc",
+      ".eslintrc",
+      "rustfmt.toml",
+      ".rustfmt.toml",
+      "clippy.toml",
+      "tsconfig.json",
+    ],
+    IconName::FileCog,
+  ),
+];
+
+/// Icons of well-known directories.
+///
+/// Lucide has no open variants of these folders, so they keep their icon while
+/// expanded.
+const DIRECTORIES: &[(&[&str], IconName)] = &[
+  (&[".git"], IconName::FolderGit2),
+  (&[".github", ".gitlab"], IconName::FolderGit),
+  (
+    &["src", "lib", "crates", "packages", "scripts"],
+    IconName::FolderCode,
+  ),
+  (
+    &["test", "tests", "spec", "__tests__"],
+    IconName::FolderCheck,
+  ),
+  (
+    &["target", "build", "dist", "out", "bin"],
+    IconName::FolderOutput,
+  ),
+  (
+    &["node_modules", "vendor", ".cargo"],
+    IconName::FolderArchive,
+  ),
+  (
+    &[".vscode", ".idea", ".zed", "config", ".config"],
+    IconName::FolderCog,
+  ),
+  (&[".ssh", "secrets"], IconName::FolderLock),
+  (&["docs", "doc"], IconName::FolderPen),
+];
+
+const MIME_TYPES: &[(&[&str], IconName)] = &[
+  (&["image/*"], IconName::FileImage),
+  (&["audio/*"], IconName::FileMusic),
+  (&["video/*"], IconName::FileVideoCamera),
+  (
+    &[
+      "font/*",
+      "application/font-woff",
+      "application/vnd.ms-fontobject",
+    ],
+    IconName::FileType,
+  ),
+  (
+    &[
+      "text/*",
+      "application/pdf",
+      "application/rtf",
+      "application/msword",
+      "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+      "application/vnd.oasis.opendocument.text",
+      "text/markdown",
+    ],
+    IconName::FileText,
+  ),
+  (
+    &[
+      "text/html",
+      "text/css",
+      "text/javascript",
+      "application/javascript",
+      "application/x-javascript",
+      "application/ecmascript",
+      "application/typescript",
+      "text/typescript",
+      "application/xml",
+      "text/xml",
+      "application/xhtml+xml",
+      "text/x-rust",
+      "text/rust",
+      "text/x-c",
+      "text/x-csrc",
+      "text/x-chdr",
+      "text/x-c++",
+      "text/x-c++src",
+      "text/x-c++hdr",
+      "text/x-go",
+      "text/x-python",
+      "text/x-script.python",
+      "text/x-java",
+      "text/x-java-source",
+      "text/x-kotlin",
+      "text/x-swift",
+      "text/x-ruby",
+      "application/x-ruby",
+      "text/x-php",
+      "application/x-httpd-php",
+      "text/x-csharp",
+      "text/x-lua",
+      "text/x-scala",
+      "text/x-haskell",
+      "application/graphql",
+      "application/wasm-text",
+    ],
+    IconName::FileCode,
+  ),
+  (
+    &[
+      "application/json",
+      "text/json",
+      "application/x-ndjson",
+      "application/manifest+json",
+    ],
+    IconName::FileBraces,
+  ),
+  (
+    &[
+      "application/toml",
+      "text/x-toml",
+      "application/yaml",
+      "application/x-yaml",
+      "text/yaml",
+      "text/x-yaml",
+    ],
+    IconName::FileCog,
+  ),
+  (
+    &[
+      "text/csv",
+      "text/tab-separated-values",
+      "application/vnd.ms-excel",
+      "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+      "application/vnd.oasis.opendocument.spreadsheet",
+    ],
+    IconName::FileSpreadsheet,
+  ),
+  (
+    &[
+      "application/zip",
+      "application/gzip",
+      "application/x-gzip",
+      "application/x-tar",
+      "application/x-bzip2",
+      "application/x-xz",
+      "application/zstd",
+      "application/x-7z-compressed",
+      "application/vnd.rar",
+      "application/x-rar-compressed",
+      "application/java-archive",
+      "application/x-iso9660-image",
+      "application/x-apple-diskimage",
+    ],
+    IconName::FileArchive,
+  ),
+  (
+    &[
+      "application/x-sh",
+      "application/x-shellscript",
+      "text/x-shellscript",
+      "text/x-sh",
+    ],
+    IconName::FileTerminal,
+  ),
+  (&["text/x-diff", "text/x-patch"], IconName::FileDiff),
+  (
+    &[
+      "application/pgp-keys",
+      "application/pgp-signature",
+      "application/x-pem-file",
+      "application/pkcs8",
+      "application/x-x509-ca-cert",
+      "application/pkix-cert",
+    ],
+    IconName::FileKey,
+  ),
+  (
+    &[
+      "application/sql",
+      "application/vnd.sqlite3",
+      "application/x-sqlite3",
+    ],
+    IconName::Database,
+  ),
+  (
+    &[
+      "application/octet-stream",
+      "application/wasm",
+      "application/x-executable",
+      "application/x-mach-binary",
+      "application/x-sharedlib",
+      "application/x-msdownload",
+    ],
+    IconName::FileBox,
+  ),
+];
+
+const LANGUAGES: &[(&[&str], IconName)] = &[
+  (
+    &[
+      "rust",
+      "c",
+      "cpp",
+      "c++",
+      "objective-c",
+      "objective-cpp",
+      "go",
+      "python",
+      "ruby",
+      "java",
+      "kotlin",
+      "swift",
+      "javascript",
+      "javascriptreact",
+      "typescript",
+      "typescriptreact",
+      "php",
+      "csharp",
+      "fsharp",
+      "lua",
+      "zig",
+      "elixir",
+      "erlang",
+      "haskell",
+      "ocaml",
+      "scala",
+      "clojure",
+      "dart",
+      "julia",
+      "nim",
+      "vue",
+      "svelte",
+      "astro",
+      "html",
+      "xml",
+      "css",
+      "scss",
+      "sass",
+      "less",
+      "graphql",
+      "proto",
+      "wgsl",
+      "glsl",
+      "hlsl",
+      "metal",
+    ],
+    IconName::FileCode,
+  ),
+  (&["json", "jsonc", "json5", "jsonl"], IconName::FileBraces),
+  (
+    &["toml", "yaml", "ini", "properties", "editorconfig"],
+    IconName::FileCog,
+  ),
+  (
+    &[
+      "markdown",
+      "plaintext",
+      "text",
+      "restructuredtext",
+      "asciidoc",
+      "org",
+      "latex",
+      "tex",
+    ],
+    IconName::FileText,
+  ),
+  (&["csv", "tsv"], IconName::FileSpreadsheet),
+  (
+    &[
+      "shellscript",
+      "shell",
+      "bash",
+      "sh",
+      "zsh",
+      "fish",
+      "nushell",
+      "powershell",
+      "bat",
+      "batch",
+    ],
+    IconName::FileTerminal,
+  ),
+  (&["sql", "plsql", "postgres", "mysql"], IconName::Database),
+  (&["diff", "git-commit", "git-rebase"], IconName::FileDiff),
+  (
+    &["ignore", "gitignore", "git-ignore", "gitattributes"],
+    IconName::GitBranch,
+  ),
+  (
+    &["dockerfile", "docker", "dockercompose"],
+    IconName::Container,
+  ),
+  (&["makefile", "make", "cmake", "just"], IconName::Hammer),
+  (&["log"], IconName::FileClock),
+];
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn file(path: &str) -> IconName {
+    icon_for_path(Path::new(path))
+  }
+
+  #[test]
+  fn test_extensions() {
+    assert_eq!(file("src/main.rs"), IconName::FileCode);
+    assert_eq!(file("photo.JPG"), IconName::FileImage);
+    assert_eq!(file("data.json"), IconName::FileBraces);
+    assert_eq!(file("notes.md"), IconName::FileText);
+    assert_eq!(file("unknown.xyz"), IconName::File);
+    assert_eq!(file("no-extension"), IconName::File);
+  }
+
+  #[test]
+  fn test_longest_extension_wins() {
+    assert_eq!(file("types.d.ts"), IconName::FileType);
+    assert_eq!(file("index.ts"), IconName::FileCode);
+    assert_eq!(file("release.tar.gz"), IconName::FileArchive);
+    assert_eq!(file("my.backup.rs"), IconName::FileCode);
+  }
+
+  #[test]
+  fn test_file_names_beat_extensions() {
+    assert_eq!(file("Cargo.toml"), IconName::Package);
+    assert_eq!(file("rustfmt.toml"), IconName::FileCog);
+    assert_eq!(file("Cargo.lock"), IconName::FileLock);
+    assert_eq!(file("Dockerfile"), IconName::Container);
+    assert_eq!(file(".gitignore"), IconName::GitBranch);
+    assert_eq!(file("README.md"), IconName::BookOpen);
+  }
+
+  #[test]
+  fn test_dotfiles_without_mapping() {
+    assert_eq!(file(".bashrc"), IconName::File);
+  }
+
+  #[test]
+  fn test_directories() {
+    let git = Path::new("repo/.git");
+    assert_eq!(icon_for_directory(git, false), IconName::FolderGit2);
+    assert_eq!(icon_for_directory(git, true), IconName::FolderGit2);
+
+    let src = Path::new("repo/src");
+    assert_eq!(icon_for_directory(src, false), IconName::FolderCode);
+    assert_eq!(icon_for_directory(src, true), IconName::FolderCode);
+
+    let other = Path::new("repo/stuff");
+    assert_eq!(icon_for_directory(other, false), IconName::Folder);
+    assert_eq!(icon_for_directory(other, true), IconName::FolderOpen);
+  }
+
+  #[test]
+  fn test_mime_types() {
+    assert_eq!(icon_for_mime_type("application/pdf"), IconName::FileText);
+    assert_eq!(icon_for_mime_type("text/x-rust"), IconName::FileCode);
+    assert_eq!(
+      icon_for_mime_type("Text/X-Rust; charset=utf-8"),
+      IconName::FileCode
+    );
+    assert_eq!(icon_for_mime_type("text/plain"), IconName::FileText);
+    assert_eq!(
+      icon_for_mime_type("application/x-sh"),
+      IconName::FileTerminal
+    );
+    assert_eq!(
+      icon_for_mime_type("application/x-msdownload"),
+      IconName::FileBox
+    );
+    assert_eq!(
+      icon_for_mime_type("application/x-executable"),
+      IconName::FileBox
+    );
+    assert_eq!(icon_for_mime_type("application/x-unknown"), IconName::File);
+    assert_eq!(icon_for_mime_type("not a mime type"), IconName::File);
+  }
+
+  #[test]
+  fn test_mime_type_fallbacks() {
+    assert_eq!(icon_for_mime_type("image/png"), IconName::FileImage);
+    assert_eq!(icon_for_mime_type("image/svg+xml"), IconName::FileImage);
+    assert_eq!(icon_for_mime_type("audio/ogg"), IconName::FileMusic);
+    assert_eq!(icon_for_mime_type("video/webm"), IconName::FileVideoCamera);
+    assert_eq!(
+      icon_for_mime_type("application/vnd.api+json"),
+      IconName::FileBraces
+    );
+    assert_eq!(
+      icon_for_mime_type("application/atom+xml"),
+      IconName::FileCode
+    );
+  }
+
+  #[test]
+  fn test_languages() {
+    assert_eq!(icon_for_language("rust"), IconName::FileCode);
+    assert_eq!(icon_for_language("TypeScriptReact"), IconName::FileCode);
+    assert_eq!(icon_for_language("json"), IconName::FileBraces);
+    assert_eq!(icon_for_language("shellscript"), IconName::FileTerminal);
+    assert_eq!(icon_for_language("dockerfile"), IconName::Container);
+    assert_eq!(icon_for_language("py"), IconName::FileCode);
+    assert_eq!(icon_for_language("klingon"), IconName::File);
+  }
+
+  #[test]
+  fn test_lookups_ignore_case() {
+    assert_eq!(file("MAIN.RS"), IconName::FileCode);
+    assert_eq!(file("Release.TAR.GZ"), IconName::FileArchive);
+    assert_eq!(file("cargo.TOML"), IconName::Package);
+    assert_eq!(
+      icon_for_directory(Path::new("repo/SRC"), true),
+      IconName::FolderCode
+    );
+    assert_eq!(
+      icon_for_directory(Path::new("repo/Node_Modules"), false),
+      IconName::FolderArchive
+    );
+    assert_eq!(icon_for_mime_type("IMAGE/PNG"), IconName::FileImage);
+  }
+
+  #[test]
+  fn test_config_overrides_and_extends() {
+    let mut icons = FileIcons::new();
+    icons
+      .extend_from_toml(
+        r#"
+        [extensions]
+        rs = "bug"
+        ".foo" = "file-heart"
+
+        [file-names]
+        "Justfile" = "file-terminal"
+
+        [directories]
+        assets = "folder-heart"
+        src = { closed = "folder-closed", open = "folder-open-dot" }
+
+        [mime-types]
+        "image/*" = "image"
+
+        [languages]
+        rust = "bug"
+        "#,
+      )
+      .unwrap();
+
+    assert_eq!(icons.icon_for_file(Path::new("a.rs")), IconName::Bug);
+    assert_eq!(icons.icon_for_mime_type("image/png"), IconName::Image);
+    assert_eq!(icons.icon_for_mime_type("text/x-rust"), IconName::FileCode);
+    assert_eq!(icons.icon_for_language("rust"), IconName::Bug);
+    assert_eq!(icons.icon_for_file(Path::new("a.foo")), IconName::FileHeart);
+    assert_eq!(
+      icons.icon_for_file(Path::new("justfile")),
+      IconName::FileTerminal
+    );
+    assert_eq!(icons.icon_for_file(Path::new("a.py")), IconName::FileCode);
+    assert_eq!(
+      icons.icon_for_directory(Path::new("assets"), true),
+      IconName::FolderHeart
+    );
+    assert_eq!(
+      icons.icon_for_directory(Path::new("src"), false),
+      IconName::FolderClosed
+    );
+    assert_eq!(
+      icons.icon_for_directory(Path::new("src"), true),
+      IconName::FolderOpenDot
+    );
+  }
+
+  #[test]
+  fn test_json_config() {
+    let mut icons = FileIcons::empty();
+    icons
+      .extend_from_json(r#"{ "extensions": { "rs": "file-code" } }"#)
+      .unwrap();
+    assert_eq!(icons.icon_for_file(Path::new("a.rs")), IconName::FileCode);
+    assert_eq!(icons.icon_for_file(Path::new("Cargo.toml")), IconName::File);
+  }
+
+  #[test]
+  fn test_invalid_config_changes_nothing() {
+    let mut icons = FileIcons::new();
+    let error = icons
+      .extend_from_toml(
+        r#"
+        [extensions]
+        rs = "file-heart"
+        py = "not-an-icon"
+        "#,
+      )
+      .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "unknown icon name in file icon config: `not-an-icon`"
+    );
+    assert_eq!(icons, FileIcons::new());
+    assert!(matches!(
+      icons.load("icons.yaml"),
+      Err(FileIconsError::UnsupportedFormat(_))
+    ));
+  }
+}
@@ -1,8 +1,19 @@
 //! Icon component for rendering SVG icons in GPUI.
 
+use crate::icon_assets::{ResolvedIcon, resolve_icon};
+use crate::icon_debug::{debug_overlay, icon_debug_enabled, take_placement};
+use crate::icon_fit::{aspect_ratio, fit_scale, resolve_view_box, warn_unapplied_fit};
+use crate::icon_gradient::gradient_image;
+use crate::icon_theme::icon_color;
+use crate::transform::warn_unapplied_origin;
+use crate::{
+  CONTRAST_GRAPHICS, IconColor, IconFit, IconGradient, IconTransform, InteractiveIcon,
+  contrasting_color,
+};
 use gpui::{
-  AnyElement, App, Hsla, IntoElement, Radians, RenderOnce, SharedString, StyleRefinement, Styled,
-  Svg, Transformation, Window, prelude::*, svg,
+  AnyElement, App, DefiniteLength, ElementId, Hsla, IntoElement, Length, ObjectFit, Pixels, Point,
+  Radians, RenderOnce, SharedString, Size, StyleRefinement, Styled, StyledImage, Svg, Window, img,
+  prelude::*, svg,
 };
 
 /// Trait for types that can provide an icon path.
@@ -28,6 +39,23 @@ use gpui::{
 /// ```
 pub trait IconNamed {
   fn path(&self) -> &'static str;
+
+  /// Returns the display name, used for labels and search.
+  ///
+  /// Defaults to the file stem of `path`, so `my-icons/logo.svg` is named `logo`.
+  fn name(&self) -> &'static str {
+    let path = self.path();
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    file_name.strip_suffix(".svg").unwrap_or(file_name)
+  }
+
+  /// Returns the `min-x`, `min-y`, `width` and `height` of the icon's viewBox.
+  ///
+  /// Generated icon sets can return the viewBox read at build time. Defaults to
+  /// `None`, in which case `Icon` reads it from the asset the first time it is drawn.
+  fn view_box(&self) -> Option<[f32; 4]> {
+    None
+  }
 }
 
 // Implement for IconName (generated enum)
@@ -35,6 +63,14 @@ impl IconNamed for crate::IconName {
   fn path(&self) -> &'static str {
     crate::IconName::path(self)
   }
+
+  fn name(&self) -> &'static str {
+    crate::IconName::name(self)
+  }
+
+  fn view_box(&self) -> Option<[f32; 4]> {
+    Some(self.vector().view_box)
+  }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -52,7 +88,7 @@ pub enum IconSize {
 }
 
 impl IconSize {
-  fn to_rems(self) -> f32 {
+  pub(crate) fn to_rems(self) -> f32 {
     match self {
       IconSize::XSmall => 0.75,
       IconSize::Small => 0.875,
@@ -61,6 +97,11 @@ impl IconSize {
       IconSize::XLarge => 2.0,
     }
   }
+
+  /// Returns the size in pixels at the default 16px rem.
+  pub(crate) fn to_pixels(self) -> f32 {
+    self.to_rems() * 16.0
+  }
 }
 
 /// An SVG icon component.
@@ -71,7 +112,7 @@ impl IconSize {
 /// # Examples
 ///
 /// ```rust,ignore
-/// use gpui_lucide::{Icon, IconName, IconSize};
+/// use gpui_lucide::{Icon, IconColor, IconName, IconSize};
 ///
 /// // Basic usage
 /// let icon = Icon::new(IconName::Heart);
@@ -80,6 +121,10 @@ impl IconSize {
 /// let icon = Icon::new(IconName::Star)
 ///     .color(gpui::rgb(0xffd700));
 ///
+/// // With a semantic color that follows light and dark mode
+/// let icon = Icon::new(IconName::TriangleAlert)
+///     .color_token(IconColor::Warning);
+///
 /// // With size
 /// let icon = Icon::new(IconName::Search)
 ///     .with_size(IconSize::Large);
@@ -88,43 +133,47 @@ impl IconSize {
 /// let icon = Icon::new(IconName::ChevronRight)
 ///     .rotate(gpui::radians(std::f32::consts::FRAC_PI_2)); // 90 degrees
 /// ```
-#[derive(IntoElement)]
+#[derive(IntoElement, Clone)]
 pub struct Icon {
-  base: Svg,
-  path: SharedString,
-  color: Option<Hsla>,
-  size: Option<IconSize>,
-  custom_style: StyleRefinement,
+  pub(crate) path: SharedString,
+  /// ViewBox of `path` when known up front.
+  pub(crate) view_box: Option<[f32; 4]>,
+  pub(crate) fit: Option<IconFit>,
+  pub(crate) color: Option<Hsla>,
+  pub(crate) color_token: Option<IconColor>,
+  /// Background and target ratio the color must contrast with.
+  pub(crate) contrast: Option<(Hsla, f32)>,
+  pub(crate) gradient: Option<IconGradient>,
+  pub(crate) size: Option<IconSize>,
+  pub(crate) transform: IconTransform,
+  pub(crate) debug: Option<bool>,
+  pub(crate) custom_style: StyleRefinement,
 }
 
 impl Default for Icon {
   fn default() -> Self {
     Self {
-      base: svg().flex_none().size_4(),
       path: "".into(),
+      view_box: None,
+      fit: None,
       color: None,
+      color_token: None,
+      contrast: None,
+      gradient: None,
       size: None,
+      transform: IconTransform::default(),
+      debug: None,
       custom_style: StyleRefinement::default(),
     }
   }
 }
 
-impl Clone for Icon {
-  fn clone(&self) -> Self {
-    Self {
-      base: svg().flex_none().size_4(),
-      path: self.path.clone(),
-      color: self.color,
-      size: self.size,
-      custom_style: self.custom_style.clone(),
-    }
-  }
-}
-
 impl Icon {
   /// Creates a new icon from any type implementing `IconNamed`.
   pub fn new(icon: impl IconNamed) -> Self {
-    Self::default().path(icon.path())
+    let mut this = Self::default().path(icon.path());
+    this.view_box = icon.view_box();
+    this
   }
 
   /// Creates a new icon from a custom path.
@@ -137,12 +186,68 @@ impl Icon {
   /// Sets the icon path.
   pub fn path(mut self, path: impl Into<SharedString>) -> Self {
     self.path = path.into();
+    self.view_box = None;
     self
   }
 
-  /// Sets the icon color.
+  /// Sets the icon color, replacing any color token.
   pub fn color(mut self, color: impl Into<Hsla>) -> Self {
     self.color = Some(color.into());
+    self.color_token = None;
+    self
+  }
+
+  /// Sets a semantic icon color, replacing any explicit color.
+  ///
+  /// The color is looked up in the [`IconTheme`](crate::IconTheme) for the window's
+  /// appearance when the icon is drawn.
+  pub fn color_token(mut self, token: IconColor) -> Self {
+    self.color_token = Some(token);
+    self.color = None;
+    self
+  }
+
+  /// Keeps the icon readable on `background`, such as a user-chosen tag color.
+  ///
+  /// The icon color, or its color token, is darkened or lightened just enough to reach
+  /// [`CONTRAST_GRAPHICS`] against `background`. Without a color, black or white is
+  /// picked, whichever contrasts more.
+  pub fn auto_contrast(self, background: impl Into<Hsla>) -> Self {
+    self.auto_contrast_with(background, CONTRAST_GRAPHICS)
+  }
+
+  /// Like [`auto_contrast`](Self::auto_contrast), with a custom WCAG contrast ratio
+  /// such as [`CONTRAST_TEXT`](crate::CONTRAST_TEXT).
+  pub fn auto_contrast_with(mut self, background: impl Into<Hsla>, target: f32) -> Self {
+    self.contrast = Some((background.into(), target));
+    self
+  }
+
+  /// Paints the icon with a linear or radial gradient instead of a single color.
+  ///
+  /// Gradient icons are drawn as SVG images with the gradient and the icon transform
+  /// baked in, cached per icon, gradient, transform and pixel size. The color set with
+  /// `color` or `color_token` is unused, and so are the hover and active colors of an
+  /// [`InteractiveIcon`], since images cannot be tinted. The transformed drawing is
+  /// clipped to the icon's bounds.
+  ///
+  /// The image is rasterized before layout, so it needs a predefined size or an
+  /// absolute width and height to stay sharp. With relative lengths such as `w_full()`
+  /// it is rasterized at its viewBox size and scaled, and a warning is logged once per
+  /// path.
+  ///
+  /// ```rust,ignore
+  /// use gpui::{linear_color_stop, rgb};
+  /// use gpui_lucide::{Icon, IconGradient, IconName};
+  ///
+  /// Icon::new(IconName::Sparkles).gradient(IconGradient::angle(
+  ///     135.,
+  ///     linear_color_stop(rgb(0x48dbfb), 0.),
+  ///     linear_color_stop(rgb(0x5f27cd), 1.),
+  /// ));
+  /// ```
+  pub fn gradient(mut self, gradient: IconGradient) -> Self {
+    self.gradient = Some(gradient);
     self
   }
 
@@ -152,19 +257,80 @@ impl Icon {
     self
   }
 
-  /// Rotates the icon by the given angle in radians.
+  /// Sets how the drawing fills a box whose aspect ratio differs from its viewBox.
+  ///
+  /// Defaults to [`IconFit::Contain`] for non-square viewBoxes and
+  /// [`IconFit::Stretch`] for square ones such as Lucide's. Only applies when the width
+  /// and height are both absolute lengths; see [`IconFit`].
+  pub fn fit(mut self, fit: IconFit) -> Self {
+    self.fit = Some(fit);
+    self
+  }
+
+  /// Rotates the icon by the given angle in radians, adding to any earlier rotation.
   pub fn rotate(mut self, radians: impl Into<Radians>) -> Self {
-    self.base = self
-      .base
-      .with_transformation(Transformation::rotate(radians));
+    self.transform = self.transform.rotate(radians);
+    self
+  }
+
+  /// Scales the icon uniformly around its transform origin.
+  pub fn scale(mut self, factor: f32) -> Self {
+    self.transform = self.transform.scale(factor);
+    self
+  }
+
+  /// Mirrors the icon left to right.
+  pub fn flip_horizontal(mut self) -> Self {
+    self.transform = self.transform.flip_horizontal();
+    self
+  }
+
+  /// Mirrors the icon top to bottom.
+  pub fn flip_vertical(mut self) -> Self {
+    self.transform = self.transform.flip_vertical();
+    self
+  }
+
+  /// Moves the icon by `offset` without affecting layout.
+  pub fn translate(mut self, offset: Point<Pixels>) -> Self {
+    self.transform = self.transform.translate(offset);
+    self
+  }
+
+  /// Sets the point scaling and rotation happen around, as a fraction of the icon
+  /// bounds. Defaults to the center.
+  ///
+  /// The origin is placed from the icon size before layout, so it needs a predefined
+  /// size or an absolute width and height. Icons sized with relative lengths such as
+  /// `w_full()` are transformed around their center, and a warning is logged once per
+  /// path.
+  pub fn transform_origin(mut self, x: f32, y: f32) -> Self {
+    self.transform = self.transform.origin(x, y);
+    self
+  }
+
+  /// Replaces the icon transform.
+  ///
+  /// See [`IconTransform`] for the order modifiers are applied in. This used to take a
+  /// `gpui::Transformation`; build the equivalent `IconTransform` instead.
+  pub fn transform(mut self, transform: IconTransform) -> Self {
+    self.transform = transform;
     self
   }
 
-  /// Applies a custom transformation to the icon.
-  pub fn transform(mut self, transformation: Transformation) -> Self {
-    self.base = self.base.with_transformation(transformation);
+  /// Shows or hides the debug overlay for this icon, overriding
+  /// [`set_icon_debug`](crate::set_icon_debug).
+  pub fn debug(mut self, enabled: bool) -> Self {
+    self.debug = Some(enabled);
     self
   }
+
+  /// Assigns an element id, making the icon interactive.
+  ///
+  /// The returned `InteractiveIcon` accepts click, hover and tooltip handlers.
+  pub fn id(self, id: impl Into<ElementId>) -> InteractiveIcon {
+    InteractiveIcon::new(id, self)
+  }
 }
 
 impl Styled for Icon {
@@ -174,27 +340,230 @@ impl Styled for Icon {
 }
 
 impl RenderOnce for Icon {
-  fn render(self, window: &mut Window, _cx: &mut App) -> impl IntoElement {
-    let text_color = self.color.unwrap_or_else(|| window.text_style().color);
-    let text_size = window.text_style().font_size.to_pixels(window.rem_size());
-
-    let has_custom_size =
-      self.custom_style.size.width.is_some() || self.custom_style.size.height.is_some();
+  fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+    self.render_debuggable(cx, |mut icon, cx| match icon.gradient.take() {
+      Some(gradient) => icon.into_gradient_img(gradient, window, cx),
+      None => icon.into_svg(window, cx).into_any_element(),
+    })
+  }
+}
 
-    let mut base = self.base;
-    *base.style() = self.custom_style;
+/// Sizing and transform of an icon, shared by its `svg()` and gradient `img()` forms.
+struct IconLayout {
+  /// Element size, when known up front.
+  element_size: Size<Pixels>,
+  transform: IconTransform,
+  text_size: Pixels,
+  size: Option<IconSize>,
+  has_custom_size: bool,
+  aspect_or_square: f32,
+  /// Aspect ratio layout derives the missing side from, when one side is set.
+  one_side_aspect: Option<f32>,
+}
 
-    base
+impl IconLayout {
+  /// Applies the default size, predefined size and aspect ratio to an element.
+  fn apply<E: Styled + FluentBuilder>(&self, element: E) -> E {
+    let text_size = self.text_size;
+    let aspect_or_square = self.aspect_or_square;
+    element
       .flex_shrink_0()
-      .text_color(text_color)
-      .when(!has_custom_size && self.size.is_none(), |this| {
-        this.size(text_size)
+      .when(!self.has_custom_size && self.size.is_none(), |this| {
+        this.h(text_size).w(text_size * aspect_or_square)
       })
       .when_some(self.size, |this, size| {
         let rems = size.to_rems();
-        this.size(gpui::rems(rems))
+        this
+          .h(gpui::rems(rems))
+          .w(gpui::rems(rems * aspect_or_square))
+      })
+      .when_some(self.one_side_aspect, |this, aspect| {
+        this.aspect_ratio(aspect)
+      })
+  }
+}
+
+impl Icon {
+  /// Renders the icon with `render`, wrapped in the debug overlay when it is enabled.
+  ///
+  /// The overlay takes over the styles that place the icon in its parent.
+  pub(crate) fn render_debuggable(
+    mut self,
+    cx: &mut App,
+    render: impl FnOnce(Self, &mut App) -> AnyElement,
+  ) -> AnyElement {
+    if !self.debug.unwrap_or_else(|| icon_debug_enabled(cx)) {
+      return render(self, cx);
+    }
+    let placement = take_placement(&mut self.custom_style);
+    let path = self.path.clone();
+    let element = render(self, cx);
+    debug_overlay(element, path, placement, cx)
+  }
+
+  /// Builds the styled `svg()` element that renders this icon.
+  ///
+  /// Paths missing from the asset source are replaced by the configured
+  /// [`IconFallback`](crate::IconFallback).
+  pub(crate) fn into_svg(self, window: &Window, cx: &mut App) -> Svg {
+    let text_color = resolve_color(self.color, self.color_token, self.contrast, window, cx);
+
+    let (path, placeholder) = match resolve_icon(self.path.clone(), cx) {
+      ResolvedIcon::Path(path) => (Some(path), None),
+      ResolvedIcon::Placeholder(color) => (None, Some(color)),
+    };
+    let view_box = match &path {
+      Some(path) if *path == self.path && self.view_box.is_some() => self.view_box,
+      Some(path) => resolve_view_box(path, cx),
+      None => None,
+    };
+    let layout = self.layout(view_box, window, cx);
+
+    let mut base = svg();
+    *base.style() = self.custom_style;
+
+    layout
+      .apply(base)
+      .when(!layout.transform.is_identity(), |this| {
+        this.with_transformation(layout.transform.to_transformation(layout.element_size))
       })
-      .path(self.path)
+      .text_color(text_color)
+      .when_some(placeholder, |this, color| this.bg(color))
+      .when_some(path, |this, path| this.path(path))
+  }
+
+  /// Builds an `img()` of this icon painted with `gradient`.
+  ///
+  /// Falls back to the `svg()` form, and so to the configured fallback, when the
+  /// asset is missing.
+  pub(crate) fn into_gradient_img(
+    self,
+    gradient: IconGradient,
+    window: &Window,
+    cx: &mut App,
+  ) -> AnyElement {
+    let path = match resolve_icon(self.path.clone(), cx) {
+      ResolvedIcon::Path(path) => path,
+      ResolvedIcon::Placeholder(_) => return self.into_svg(window, cx).into_any_element(),
+    };
+    let view_box = match self.view_box {
+      Some(view_box) if path == self.path => Some(view_box),
+      _ => resolve_view_box(&path, cx),
+    };
+    let layout = self.layout(view_box, window, cx);
+    let image = gradient_image(
+      &path,
+      &gradient,
+      &layout.transform,
+      view_box,
+      layout.element_size,
+      cx,
+    );
+    let Some(image) = image else {
+      return self.into_svg(window, cx).into_any_element();
+    };
+
+    let mut base = img(image).object_fit(ObjectFit::Fill);
+    *base.style() = self.custom_style;
+
+    layout.apply(base).into_any_element()
+  }
+
+  /// Computes the size and transform of the icon for a content `view_box`.
+  fn layout(&self, view_box: Option<[f32; 4]>, window: &Window, cx: &mut App) -> IconLayout {
+    let text_size = window.text_style().font_size.to_pixels(window.rem_size());
+    let content_aspect = view_box.and_then(aspect_ratio);
+    // Square icons keep square sizes; others take the aspect ratio of their viewBox.
+    let aspect = content_aspect.filter(|aspect| (aspect - 1.0).abs() > 1e-3);
+    let aspect_or_square = aspect.unwrap_or(1.0);
+
+    let width = self.custom_style.size.width;
+    let height = self.custom_style.size.height;
+    let has_custom_size = width.is_some() || height.is_some();
+
+    // A custom transform origin needs the element size, which is only known up front
+    // for predefined and absolute sizes. Other sizes transform around the center.
+    let rem_size = window.rem_size();
+    let custom_width = absolute_pixels(width, rem_size);
+    let custom_height = absolute_pixels(height, rem_size);
+    let element_size = match self.size {
+      Some(size) => {
+        let pixels = gpui::rems(size.to_rems()).to_pixels(rem_size);
+        gpui::size(pixels * aspect_or_square, pixels)
+      }
+      None if !has_custom_size => gpui::size(text_size * aspect_or_square, text_size),
+      None => match (custom_width, custom_height, content_aspect) {
+        (Some(width), None, Some(aspect)) => gpui::size(width, width / aspect),
+        (None, Some(height), Some(aspect)) => gpui::size(height * aspect, height),
+        (width, height, _) => gpui::size(
+          width.unwrap_or(Pixels::ZERO),
+          height.unwrap_or(Pixels::ZERO),
+        ),
+      },
+    };
+
+    // With both sides set, the fit mode corrects the stretch gpui draws SVGs with.
+    let fit = self.fit.unwrap_or(if aspect.is_some() {
+      IconFit::Contain
+    } else {
+      IconFit::Stretch
+    });
+    if let Some(fit) = self.fit
+      && self.size.is_none()
+      && width.is_some()
+      && height.is_some()
+      && custom_width.zip(custom_height).is_none()
+    {
+      warn_unapplied_fit(&self.path, fit, cx);
+    }
+    let transform = match (content_aspect, self.size, custom_width.zip(custom_height)) {
+      (Some(aspect), None, Some(_)) => {
+        let (x, y) = fit_scale(aspect, element_size, fit);
+        self.transform.scale_xy(x, y)
+      }
+      _ => self.transform,
+    };
+    if self.transform.has_origin()
+      && (element_size.width == Pixels::ZERO || element_size.height == Pixels::ZERO)
+    {
+      warn_unapplied_origin(&self.path, cx);
+    }
+    // With one side set, layout derives the other from the aspect ratio.
+    let one_side_aspect =
+      content_aspect.filter(|_| self.size.is_none() && width.is_some() != height.is_some());
+
+    IconLayout {
+      element_size,
+      transform,
+      text_size,
+      size: self.size,
+      has_custom_size,
+      aspect_or_square,
+      one_side_aspect,
+    }
+  }
+}
+
+/// Resolves the color an icon is drawn with: the explicit color, else the color token,
+/// else the text color, adjusted to the requested contrast.
+pub(crate) fn resolve_color(
+  color: Option<Hsla>,
+  token: Option<IconColor>,
+  contrast: Option<(Hsla, f32)>,
+  window: &Window,
+  cx: &App,
+) -> Hsla {
+  let color = color.or_else(|| token.map(|token| icon_color(token, window, cx)));
+  match contrast {
+    Some((background, target)) => contrasting_color(color, background, target),
+    None => color.unwrap_or_else(|| window.text_style().color),
+  }
+}
+
+fn absolute_pixels(length: Option<Length>, rem_size: Pixels) -> Option<Pixels> {
+  match length {
+    Some(Length::Definite(DefiniteLength::Absolute(length))) => Some(length.to_pixels(rem_size)),
+    _ => None,
   }
 }
 
@@ -228,6 +597,12 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_icon_named_default_name_is_file_stem() {
+    assert_eq!(IconNamed::name(&TestIcon::Sample), "sample");
+    assert_eq!(IconNamed::name(&crate::IconName::ArrowDown), "arrow-down");
+  }
+
   #[test]
   fn test_icon_size_to_rems() {
     assert_eq!(IconSize::XSmall.to_rems(), 0.75);
@@ -237,6 +612,12 @@ mod tests {
     assert_eq!(IconSize::XLarge.to_rems(), 2.0);
   }
 
+  #[test]
+  fn test_icon_size_to_pixels() {
+    assert_eq!(IconSize::Small.to_pixels(), 14.0);
+    assert_eq!(IconSize::Large.to_pixels(), 24.0);
+  }
+
   #[test]
   fn test_new_uses_icon_named_path() {
     let icon = Icon::new(TestIcon::Sample);
@@ -261,6 +642,42 @@ mod tests {
     assert!(icon.color.is_some());
   }
 
+  #[test]
+  fn test_color_and_color_token_replace_each_other() {
+    let icon = Icon::default()
+      .color(rgb(0xff0000))
+      .color_token(IconColor::Danger);
+    assert_eq!(icon.color, None);
+    assert_eq!(icon.color_token, Some(IconColor::Danger));
+
+    let icon = icon.color(rgb(0xff0000));
+    assert!(icon.color.is_some());
+    assert_eq!(icon.color_token, None);
+  }
+
+  #[test]
+  fn test_auto_contrast_sets_background_and_target() {
+    let icon = Icon::default().auto_contrast(rgb(0xfeca57));
+    assert_eq!(
+      icon.contrast,
+      Some((rgb(0xfeca57).into(), CONTRAST_GRAPHICS))
+    );
+
+    let icon = icon.auto_contrast_with(rgb(0x5f27cd), crate::CONTRAST_TEXT);
+    assert_eq!(icon.contrast.map(|(_, target)| target), Some(4.5));
+  }
+
+  #[test]
+  fn test_gradient_sets_gradient() {
+    let icon = Icon::default().gradient(IconGradient::angle(
+      90.,
+      gpui::linear_color_stop(rgb(0xfeca57), 0.),
+      gpui::linear_color_stop(rgb(0xe94560), 1.),
+    ));
+    assert!(icon.gradient.is_some());
+    assert!(icon.clone().gradient.is_some());
+  }
+
   #[test]
   fn test_from_icon_name_uses_generated_path() {
     let icon: Icon = crate::IconName::Heart.into();
@@ -279,5 +696,63 @@ mod tests {
     assert_eq!(cloned.path.as_ref(), "icons/sample.svg");
     assert!(cloned.color.is_some());
     assert_eq!(cloned.size, Some(IconSize::Small));
+    assert_eq!(cloned.transform, icon.transform);
+    assert!(!cloned.transform.is_identity());
+  }
+
+  #[test]
+  fn test_modifiers_compose() {
+    let quarter = gpui::radians(std::f32::consts::FRAC_PI_2);
+    let icon = Icon::from_path("icons/sample.svg")
+      .rotate(quarter)
+      .flip_horizontal()
+      .rotate(quarter)
+      .scale(2.0)
+      .translate(gpui::point(gpui::px(1.0), gpui::px(2.0)));
+
+    let expected = IconTransform::new()
+      .rotate(gpui::radians(std::f32::consts::PI))
+      .scale_xy(-2.0, 2.0)
+      .translate(gpui::point(gpui::px(1.0), gpui::px(2.0)));
+    assert_eq!(icon.transform, expected);
+  }
+
+  #[test]
+  fn test_new_takes_view_box_from_icon_named() {
+    assert_eq!(
+      Icon::new(crate::IconName::Heart).view_box,
+      Some([0.0, 0.0, 24.0, 24.0])
+    );
+    assert_eq!(Icon::new(TestIcon::Sample).view_box, None);
+    assert_eq!(
+      Icon::new(crate::IconName::Heart)
+        .path("icons/logo.svg")
+        .view_box,
+      None
+    );
+  }
+
+  #[test]
+  fn test_fit_sets_fit() {
+    assert_eq!(Icon::default().fit, None);
+    assert_eq!(
+      Icon::default().fit(IconFit::Cover).fit,
+      Some(IconFit::Cover)
+    );
+  }
+
+  #[test]
+  fn test_debug_overrides_global_setting() {
+    assert_eq!(Icon::default().debug, None);
+    assert_eq!(Icon::default().debug(true).debug, Some(true));
+    assert_eq!(Icon::default().debug(false).debug, Some(false));
+  }
+
+  #[test]
+  fn test_transform_replaces_modifiers() {
+    let icon = Icon::default()
+      .rotate(gpui::radians(1.0))
+      .transform(IconTransform::new().scale(0.5));
+    assert_eq!(icon.transform, IconTransform::new().scale(0.5));
   }
 }
@@ -0,0 +1,305 @@
+//! Checks that icon paths resolve in the app's `AssetSource`, and the fallback drawn
+//! when they do not.
+
+use crate::{IconName, IconNamed};
+use gpui::{App, AssetSource, Global, Hsla, SharedString, rgb};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Error for an icon path that cannot be loaded from an `AssetSource`.
+#[derive(Debug, Clone)]
+pub enum IconError {
+  /// The asset source has no asset at the path.
+  NotFound { path: SharedString },
+  /// The asset source failed to load the path.
+  Load {
+    path: SharedString,
+    source: Arc<dyn std::error::Error + Send + Sync>,
+  },
+}
+
+impl IconError {
+  /// Returns the path that failed to resolve.
+  pub fn path(&self) -> &SharedString {
+    match self {
+      Self::NotFound { path } | Self::Load { path, .. } => path,
+    }
+  }
+}
+
+impl fmt::Display for IconError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::NotFound { path } => write!(f, "icon asset not found: `{}`", path),
+      Self::Load { path, source } => {
+        write!(f, "failed to load icon asset `{}`: {}", path, source)
+      }
+    }
+  }
+}
+
+impl std::error::Error for IconError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Self::NotFound { .. } => None,
+      Self::Load { source, .. } => Some(source.as_ref()),
+    }
+  }
+}
+
+/// Checks that `path` loads from `assets`.
+pub fn check_icon_path(path: &str, assets: &dyn AssetSource) -> Result<(), IconError> {
+  match assets.load(path) {
+    Ok(Some(_)) => Ok(()),
+    Ok(None) => Err(IconError::NotFound {
+      path: SharedString::from(path.to_string()),
+    }),
+    Err(error) => Err(IconError::Load {
+      path: SharedString::from(path.to_string()),
+      source: Arc::from(Box::<dyn std::error::Error + Send + Sync>::from(error)),
+    }),
+  }
+}
+
+/// Checks that every icon's path loads from `assets`, returning all failures.
+///
+/// Run it at startup or in a test to catch icons that would fall back at render time.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{IconName, check_icon_assets};
+///
+/// #[test]
+/// fn icons_are_bundled() {
+///     check_icon_assets(IconName::all(), &Assets).unwrap();
+/// }
+/// ```
+pub fn check_icon_assets<I: IconNamed>(
+  icons: impl IntoIterator<Item = I>,
+  assets: &dyn AssetSource,
+) -> Result<(), Vec<IconError>> {
+  let errors: Vec<_> = icons
+    .into_iter()
+    .filter_map(|icon| check_icon_path(icon.path(), assets).err())
+    .collect();
+  if errors.is_empty() {
+    Ok(())
+  } else {
+    Err(errors)
+  }
+}
+
+/// What an `Icon` draws when its path does not resolve.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IconFallback {
+  /// Draws nothing.
+  Nothing,
+  /// Draws the icon at another path instead.
+  Icon(SharedString),
+  /// Fills the icon bounds with a color.
+  Placeholder(Hsla),
+}
+
+impl IconFallback {
+  /// Draws `icon` instead of missing icons.
+  pub fn icon(icon: impl IconNamed) -> Self {
+    Self::Icon(icon.path().into())
+  }
+}
+
+impl Default for IconFallback {
+  /// A red box in debug builds, so missing icons stand out, and `ImageOff` otherwise.
+  fn default() -> Self {
+    if cfg!(debug_assertions) {
+      Self::Placeholder(rgb(0xff0000).into())
+    } else {
+      Self::icon(IconName::ImageOff)
+    }
+  }
+}
+
+/// Sets what icons draw when their path is missing from the `AssetSource`.
+///
+/// Defaults to [`IconFallback::default`]. Every missing path is also logged once as a
+/// warning. A fallback icon is checked right away; when it is missing too, icons with
+/// missing paths draw nothing.
+pub fn set_icon_fallback(cx: &mut App, fallback: IconFallback) {
+  let assets = cx.asset_source().clone();
+  cx.default_global::<IconAssets>()
+    .set_fallback(fallback, assets.as_ref());
+}
+
+/// Fallback setting and the result of loading each path, checked once per path.
+#[derive(Default)]
+struct IconAssets {
+  fallback: IconFallback,
+  checked: HashMap<SharedString, bool>,
+}
+
+impl Global for IconAssets {}
+
+impl IconAssets {
+  fn set_fallback(&mut self, fallback: IconFallback, assets: &dyn AssetSource) {
+    if let IconFallback::Icon(path) = &fallback {
+      self.is_loadable(path, assets);
+    }
+    self.fallback = fallback;
+  }
+
+  /// Returns whether `path` loads, checking and logging a failure only the first time.
+  fn is_loadable(&mut self, path: &SharedString, assets: &dyn AssetSource) -> bool {
+    if let Some(found) = self.checked.get(path) {
+      return *found;
+    }
+    let result = check_icon_path(path, assets);
+    if let Err(error) = &result {
+      log::warn!("{}", error);
+    }
+    self.checked.insert(path.clone(), result.is_ok());
+    result.is_ok()
+  }
+
+  fn resolve(&mut self, path: SharedString, assets: &dyn AssetSource) -> ResolvedIcon {
+    // `Icon::default()` has no path and draws nothing, which is not an error.
+    if path.is_empty() || self.is_loadable(&path, assets) {
+      return ResolvedIcon::Path(path);
+    }
+    match self.fallback.clone() {
+      IconFallback::Icon(fallback) if self.is_loadable(&fallback, assets) => {
+        ResolvedIcon::Path(fallback)
+      }
+      IconFallback::Nothing | IconFallback::Icon(_) => ResolvedIcon::Path(path),
+      IconFallback::Placeholder(color) => ResolvedIcon::Placeholder(color),
+    }
+  }
+}
+
+/// What an `Icon` should draw for a path.
+#[derive(Debug, PartialEq)]
+pub(crate) enum ResolvedIcon {
+  Path(SharedString),
+  Placeholder(Hsla),
+}
+
+/// Resolves `path` against the app's `AssetSource`, applying the fallback when it is
+/// missing. The first failure for each path is logged.
+pub(crate) fn resolve_icon(path: SharedString, cx: &mut App) -> ResolvedIcon {
+  let assets = cx.asset_source().clone();
+  cx.default_global::<IconAssets>()
+    .resolve(path, assets.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::borrow::Cow;
+
+  struct TestAssets;
+
+  impl AssetSource for TestAssets {
+    fn load(&self, path: &str) -> anyhow::Result<Option<Cow<'static, [u8]>>> {
+      match path {
+        "icons/heart.svg" | "icons/x.svg" => Ok(Some(Cow::Borrowed(b"<svg/>"))),
+        "icons/locked.svg" => Err(anyhow::anyhow!("permission denied")),
+        _ => Ok(None),
+      }
+    }
+
+    fn list(&self, _path: &str) -> anyhow::Result<Vec<SharedString>> {
+      Ok(Vec::new())
+    }
+  }
+
+  #[test]
+  fn test_check_icon_path() {
+    assert!(check_icon_path("icons/heart.svg", &TestAssets).is_ok());
+
+    let missing = check_icon_path("icons/nope.svg", &TestAssets).unwrap_err();
+    assert!(matches!(missing, IconError::NotFound { .. }));
+    assert_eq!(
+      missing.to_string(),
+      "icon asset not found: `icons/nope.svg`"
+    );
+
+    let locked = check_icon_path("icons/locked.svg", &TestAssets).unwrap_err();
+    assert_eq!(locked.path().as_ref(), "icons/locked.svg");
+    assert_eq!(
+      locked.to_string(),
+      "failed to load icon asset `icons/locked.svg`: permission denied"
+    );
+    assert!(std::error::Error::source(&locked).is_some());
+  }
+
+  #[test]
+  fn test_check_icon_assets_collects_every_failure() {
+    assert!(check_icon_assets([IconName::Heart, IconName::X], &TestAssets).is_ok());
+
+    let errors = check_icon_assets(
+      [IconName::Heart, IconName::Star, IconName::Moon],
+      &TestAssets,
+    )
+    .unwrap_err();
+    let paths: Vec<_> = errors.iter().map(|error| error.path().as_ref()).collect();
+    assert_eq!(paths, ["icons/star.svg", "icons/moon.svg"]);
+  }
+
+  #[test]
+  fn test_resolve_applies_the_fallback() {
+    let mut icons = IconAssets::default();
+    icons.set_fallback(IconFallback::icon(IconName::X), &TestAssets);
+    assert_eq!(
+      icons.checked.get(&SharedString::from("icons/x.svg")),
+      Some(&true)
+    );
+
+    assert_eq!(
+      icons.resolve("icons/heart.svg".into(), &TestAssets),
+      ResolvedIcon::Path("icons/heart.svg".into())
+    );
+    assert_eq!(
+      icons.resolve("icons/nope.svg".into(), &TestAssets),
+      ResolvedIcon::Path("icons/x.svg".into())
+    );
+    assert_eq!(
+      icons.checked.get(&SharedString::from("icons/nope.svg")),
+      Some(&false)
+    );
+  }
+
+  #[test]
+  fn test_missing_fallback_icon_draws_nothing() {
+    let mut icons = IconAssets::default();
+    icons.set_fallback(IconFallback::icon(IconName::Star), &TestAssets);
+    assert_eq!(
+      icons.checked.get(&SharedString::from("icons/star.svg")),
+      Some(&false)
+    );
+    assert_eq!(
+      icons.resolve("icons/nope.svg".into(), &TestAssets),
+      ResolvedIcon::Path("icons/nope.svg".into())
+    );
+  }
+
+  #[test]
+  fn test_empty_path_is_not_checked() {
+    let mut icons = IconAssets::default();
+    icons.set_fallback(IconFallback::Placeholder(rgb(0xff0000).into()), &TestAssets);
+    assert_eq!(
+      icons.resolve("".into(), &TestAssets),
+      ResolvedIcon::Path("".into())
+    );
+    assert!(icons.checked.is_empty());
+  }
+
+  #[test]
+  fn test_default_fallback() {
+    let fallback = IconFallback::default();
+    if cfg!(debug_assertions) {
+      assert!(matches!(fallback, IconFallback::Placeholder(_)));
+    } else {
+      assert_eq!(fallback, IconFallback::Icon("icons/image-off.svg".into()));
+    }
+  }
+}
@@ -0,0 +1,304 @@
+//! Debug overlay showing how an icon is laid out.
+
+use crate::icon_assets::{ResolvedIcon, resolve_icon};
+use crate::icon_fit::resolve_view_box;
+use gpui::{
+  AnyElement, App, Bounds, Global, Hsla, IntoElement, Pixels, Refineable, SharedString,
+  StyleRefinement, Styled, TextAlign, TextRun, Window, canvas, div, fill, hsla, point, prelude::*,
+  px, size,
+};
+use std::mem;
+
+/// The Lucide viewBox, assumed for icons whose viewBox cannot be read.
+const LUCIDE_VIEW_BOX: [f32; 4] = [0.0, 0.0, 24.0, 24.0];
+
+/// Padding Lucide keeps clear inside its viewBox, in units.
+const PADDING: f32 = 1.0;
+
+/// Most grid lines drawn across one side, so large viewBoxes stay readable.
+const MAX_GRID_LINES: f32 = 24.0;
+
+/// Colors of the icon debug overlay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconDebugStyle {
+  /// Outline of the icon's layout bounds.
+  pub bounds: Hsla,
+  /// Lines of the viewBox grid, one per unit up to 24 lines per side.
+  pub grid: Hsla,
+  /// Guides of the padding inside the viewBox.
+  pub padding: Hsla,
+  /// Text of the path and size label.
+  pub label: Hsla,
+}
+
+impl Default for IconDebugStyle {
+  fn default() -> Self {
+    Self {
+      bounds: hsla(0.0, 1.0, 0.5, 0.9),
+      grid: hsla(0.55, 1.0, 0.5, 0.15),
+      padding: hsla(0.3, 1.0, 0.4, 0.7),
+      label: hsla(0.0, 1.0, 0.5, 1.0),
+    }
+  }
+}
+
+#[derive(Default)]
+struct IconDebug {
+  enabled: bool,
+  style: IconDebugStyle,
+}
+
+impl Global for IconDebug {}
+
+/// Turns the debug overlay on or off for every `Icon` without its own setting.
+///
+/// The overlay outlines the layout bounds, draws the grid of the icon's viewBox with
+/// Lucide's 1-unit padding guides on 24-unit icons, and labels the icon with its
+/// resolved path and pixel size. Use [`Icon::debug`](crate::Icon::debug) to override it per icon.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{Icon, IconName, set_icon_debug};
+///
+/// set_icon_debug(cx, true);
+/// let icon = Icon::new(IconName::Heart).debug(false);
+/// ```
+pub fn set_icon_debug(cx: &mut App, enabled: bool) {
+  cx.default_global::<IconDebug>().enabled = enabled;
+}
+
+/// Returns true when the debug overlay is on globally.
+pub fn icon_debug_enabled(cx: &App) -> bool {
+  cx.try_global::<IconDebug>()
+    .is_some_and(|debug| debug.enabled)
+}
+
+/// Sets the colors of the debug overlay.
+pub fn set_icon_debug_style(cx: &mut App, style: IconDebugStyle) {
+  cx.default_global::<IconDebug>().style = style;
+}
+
+/// Moves the styles that place an element in its parent out of `style`, leaving the
+/// ones that shape the element itself.
+///
+/// The debug overlay wraps the icon, so the wrapper has to take the icon's place.
+pub(crate) fn take_placement(style: &mut StyleRefinement) -> StyleRefinement {
+  StyleRefinement {
+    position: style.position.take(),
+    inset: mem::take(&mut style.inset),
+    margin: mem::take(&mut style.margin),
+    flex_grow: style.flex_grow.take(),
+    flex_shrink: style.flex_shrink.take(),
+    flex_basis: style.flex_basis.take(),
+    align_self: style.align_self.take(),
+    ..StyleRefinement::default()
+  }
+}
+
+/// Wraps a rendered icon in the debug overlay, placing the wrapper with `placement`
+/// from [`take_placement`].
+pub(crate) fn debug_overlay(
+  icon: AnyElement,
+  path: SharedString,
+  placement: StyleRefinement,
+  cx: &mut App,
+) -> AnyElement {
+  let style = cx
+    .try_global::<IconDebug>()
+    .map(|debug| debug.style)
+    .unwrap_or_default();
+  let (resolved, view_box): (SharedString, _) = match resolve_icon(path, cx) {
+    ResolvedIcon::Path(path) => {
+      let view_box = resolve_view_box(&path, cx).unwrap_or(LUCIDE_VIEW_BOX);
+      (path, view_box)
+    }
+    ResolvedIcon::Placeholder(_) => ("missing (placeholder)".into(), LUCIDE_VIEW_BOX),
+  };
+
+  let overlay = canvas(
+    |_, _, _| {},
+    move |bounds, _, window, cx| {
+      let (origin, unit) = view_box_frame(bounds, view_box);
+      let [_, _, width, height] = view_box;
+      let frame = size(unit * width, unit * height);
+      for x in grid_lines(width) {
+        window.paint_quad(fill(
+          Bounds::new(
+            point(origin.x + unit * x, origin.y),
+            size(px(1.), frame.height),
+          ),
+          style.grid,
+        ));
+      }
+      for y in grid_lines(height) {
+        window.paint_quad(fill(
+          Bounds::new(
+            point(origin.x, origin.y + unit * y),
+            size(frame.width, px(1.)),
+          ),
+          style.grid,
+        ));
+      }
+
+      if view_box == LUCIDE_VIEW_BOX {
+        let inset = unit * PADDING;
+        let padding = Bounds::new(
+          point(origin.x + inset, origin.y + inset),
+          size(frame.width - inset * 2., frame.height - inset * 2.),
+        );
+        paint_outline(padding, style.padding, window);
+      }
+      paint_outline(bounds, style.bounds, window);
+
+      let text = debug_label(&resolved, bounds);
+      let font_size = px(9.);
+      let run = TextRun {
+        len: text.len(),
+        font: window.text_style().font(),
+        color: style.label,
+        background_color: None,
+        underline: None,
+        strikethrough: None,
+      };
+      let line = window
+        .text_system()
+        .shape_line(text, font_size, &[run], None);
+      line
+        .paint(
+          point(bounds.left(), bounds.bottom() + px(2.)),
+          font_size * 1.25,
+          TextAlign::Left,
+          None,
+          window,
+          cx,
+        )
+        .ok();
+    },
+  )
+  .absolute()
+  .top_0()
+  .left_0()
+  .size_full();
+
+  let mut wrapper = div().relative().flex_none();
+  wrapper.style().refine(&placement);
+  wrapper.child(icon).child(overlay).into_any_element()
+}
+
+/// Returns the origin and size of one unit of `view_box`, fitted and centered in
+/// `bounds` the way the SVG is drawn.
+fn view_box_frame(bounds: Bounds<Pixels>, view_box: [f32; 4]) -> (gpui::Point<Pixels>, Pixels) {
+  let [_, _, width, height] = view_box;
+  if width <= 0.0 || height <= 0.0 {
+    return (bounds.origin, Pixels::ZERO);
+  }
+  let unit = (f32::from(bounds.size.width) / width).min(f32::from(bounds.size.height) / height);
+  let origin = point(
+    bounds.left() + (bounds.size.width - px(unit * width)) / 2.,
+    bounds.top() + (bounds.size.height - px(unit * height)) / 2.,
+  );
+  (origin, px(unit))
+}
+
+/// Returns the offsets, in viewBox units, of the grid lines across `extent` units:
+/// one per unit, or fewer for large viewBoxes.
+fn grid_lines(extent: f32) -> impl Iterator<Item = f32> {
+  let step = (extent / MAX_GRID_LINES).ceil().max(1.0);
+  let count = (extent / step).floor().max(0.0) as usize;
+  (0..=count).map(move |index| index as f32 * step)
+}
+
+/// Formats the overlay label, such as `icons/heart.svg 24×24px`.
+fn debug_label(path: &str, bounds: Bounds<Pixels>) -> SharedString {
+  format!(
+    "{} {}×{}px",
+    path,
+    f32::from(bounds.size.width).round(),
+    f32::from(bounds.size.height).round()
+  )
+  .into()
+}
+
+/// Strokes the edges of `bounds` with 1px lines.
+fn paint_outline(bounds: Bounds<Pixels>, color: Hsla, window: &mut Window) {
+  let width = bounds.size.width;
+  let height = bounds.size.height;
+  let edges = [
+    Bounds::new(bounds.origin, size(width, px(1.))),
+    Bounds::new(
+      point(bounds.left(), bounds.bottom() - px(1.)),
+      size(width, px(1.)),
+    ),
+    Bounds::new(bounds.origin, size(px(1.), height)),
+    Bounds::new(
+      point(bounds.right() - px(1.), bounds.top()),
+      size(px(1.), height),
+    ),
+  ];
+  for edge in edges {
+    window.paint_quad(fill(edge, color));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_view_box_frame_centers_the_square() {
+    let bounds = Bounds::new(point(px(10.), px(20.)), size(px(48.), px(24.)));
+    let (origin, unit) = view_box_frame(bounds, LUCIDE_VIEW_BOX);
+    assert_eq!(origin, point(px(22.), px(20.)));
+    assert_eq!(unit, px(1.));
+  }
+
+  #[test]
+  fn test_view_box_frame_follows_the_view_box() {
+    let bounds = Bounds::new(point(px(0.), px(0.)), size(px(40.), px(40.)));
+    let (origin, unit) = view_box_frame(bounds, [0.0, 0.0, 100.0, 50.0]);
+    assert_eq!(origin, point(px(0.), px(10.)));
+    assert_eq!(unit, px(0.4));
+  }
+
+  #[test]
+  fn test_grid_lines() {
+    assert_eq!(grid_lines(24.0).count(), 25);
+    assert_eq!(grid_lines(16.0).last(), Some(16.0));
+    let lines: Vec<f32> = grid_lines(100.0).collect();
+    assert_eq!(lines.len(), 21);
+    assert_eq!(lines[1], 5.0);
+  }
+
+  #[test]
+  fn test_debug_label() {
+    let bounds = Bounds::new(point(px(0.), px(0.)), size(px(15.6), px(16.)));
+    assert_eq!(
+      debug_label("icons/heart.svg", bounds).as_ref(),
+      "icons/heart.svg 16×16px"
+    );
+  }
+
+  #[test]
+  fn test_take_placement_leaves_the_icon_size() {
+    let mut style = StyleRefinement::default()
+      .absolute()
+      .top_0()
+      .m_1()
+      .flex_grow()
+      .size_4();
+    let placement = take_placement(&mut style);
+
+    assert_eq!(placement.position, Some(gpui::Position::Absolute));
+    assert!(placement.inset.top.is_some());
+    assert!(placement.margin.left.is_some());
+    assert_eq!(placement.flex_grow, Some(1.));
+    assert!(placement.size.width.is_none());
+
+    assert_eq!(style.position, None);
+    assert!(style.inset.top.is_none());
+    assert!(style.margin.left.is_none());
+    assert_eq!(style.flex_grow, None);
+    assert!(style.size.width.is_some());
+  }
+}
@@ -0,0 +1,147 @@
+//! Fitting icons whose viewBox is not square.
+
+use crate::{IconName, parse_view_box};
+use gpui::{App, Global, Pixels, SharedString, Size};
+use std::collections::{HashMap, HashSet};
+
+/// How an icon's drawing fills a box whose aspect ratio differs from its viewBox.
+///
+/// Only applies when both the width and the height of the icon are set to absolute
+/// lengths, such as `px` or `rems`. With one of them set, or none, the icon takes the
+/// aspect ratio of its viewBox. Relative lengths such as `w_full()` are only known
+/// after layout, too late to fit the drawing, so it is stretched to the box and a
+/// warning is logged once per path.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{Icon, IconFit};
+///
+/// // Only the height is set, so the width follows the viewBox.
+/// Icon::new(MyLogo).h(gpui::px(20.));
+/// // Both sides are set, so the fit decides how the logo fills the square.
+/// Icon::new(MyLogo).size(gpui::px(32.)).fit(IconFit::Contain);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconFit {
+  /// Scales the drawing to fit inside the box, keeping its aspect ratio. The default
+  /// for icons with a non-square viewBox.
+  Contain,
+  /// Scales the drawing to cover the box, keeping its aspect ratio. Parts outside the
+  /// box are drawn unless a parent clips them.
+  Cover,
+  /// Stretches the drawing to the box. The default for square icons.
+  Stretch,
+}
+
+/// ViewBoxes read from runtime asset paths, keyed by path.
+#[derive(Default)]
+struct ViewBoxCache {
+  entries: HashMap<SharedString, Option<[f32; 4]>>,
+}
+
+impl Global for ViewBoxCache {}
+
+/// Paths already warned about by [`warn_unapplied_fit`].
+#[derive(Default)]
+struct FitWarnings {
+  paths: HashSet<SharedString>,
+}
+
+impl Global for FitWarnings {}
+
+/// Logs, once per path, that an explicit fit could not be applied to an icon sized
+/// with relative lengths.
+pub(crate) fn warn_unapplied_fit(path: &SharedString, fit: IconFit, cx: &mut App) {
+  if cx
+    .default_global::<FitWarnings>()
+    .paths
+    .insert(path.clone())
+  {
+    log::warn!(
+      "IconFit::{:?} is ignored for `{}`: fitting needs an absolute width and height",
+      fit,
+      path
+    );
+  }
+}
+
+/// Returns the viewBox of the SVG at `path`.
+///
+/// Bundled Lucide icons use the viewBox read at build time, other paths are loaded
+/// from the app's `AssetSource` once and cached.
+pub(crate) fn resolve_view_box(path: &SharedString, cx: &mut App) -> Option<[f32; 4]> {
+  if let Some(icon) = IconName::from_path(path) {
+    return Some(icon.vector().view_box);
+  }
+
+  if let Some(entry) = cx.default_global::<ViewBoxCache>().entries.get(path) {
+    return *entry;
+  }
+
+  let view_box = cx
+    .asset_source()
+    .load(path)
+    .ok()
+    .flatten()
+    .and_then(|bytes| parse_view_box(std::str::from_utf8(&bytes).ok()?));
+
+  cx.default_global::<ViewBoxCache>()
+    .entries
+    .insert(path.clone(), view_box);
+  view_box
+}
+
+/// Returns width divided by height of a viewBox, or `None` when it is degenerate.
+pub(crate) fn aspect_ratio(view_box: [f32; 4]) -> Option<f32> {
+  let [_, _, width, height] = view_box;
+  let aspect = width / height;
+  (aspect.is_finite() && aspect > 0.0).then_some(aspect)
+}
+
+/// Returns the scale that turns a drawing stretched to `frame` into one fitted with
+/// `fit`, for a drawing of the given aspect ratio.
+pub(crate) fn fit_scale(aspect: f32, frame: Size<Pixels>, fit: IconFit) -> (f32, f32) {
+  let width = f32::from(frame.width);
+  let height = f32::from(frame.height);
+  if width <= 0.0 || height <= 0.0 {
+    return (1.0, 1.0);
+  }
+
+  let (drawn_width, drawn_height) = match fit {
+    IconFit::Stretch => return (1.0, 1.0),
+    IconFit::Contain => (width.min(height * aspect), height.min(width / aspect)),
+    IconFit::Cover => (width.max(height * aspect), height.max(width / aspect)),
+  };
+  (drawn_width / width, drawn_height / height)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use gpui::{px, size};
+
+  #[test]
+  fn test_aspect_ratio() {
+    assert_eq!(aspect_ratio([0.0, 0.0, 24.0, 24.0]), Some(1.0));
+    assert_eq!(aspect_ratio([0.0, 0.0, 64.0, 16.0]), Some(4.0));
+    assert_eq!(aspect_ratio([0.0, 0.0, 64.0, 0.0]), None);
+    assert_eq!(aspect_ratio([0.0, 0.0, 0.0, 16.0]), None);
+  }
+
+  #[test]
+  fn test_fit_scale() {
+    let frame = size(px(32.), px(32.));
+    assert_eq!(fit_scale(2.0, frame, IconFit::Contain), (1.0, 0.5));
+    assert_eq!(fit_scale(2.0, frame, IconFit::Cover), (2.0, 1.0));
+    assert_eq!(fit_scale(2.0, frame, IconFit::Stretch), (1.0, 1.0));
+    assert_eq!(
+      fit_scale(1.0, size(px(40.), px(20.)), IconFit::Contain),
+      (0.5, 1.0)
+    );
+    assert_eq!(
+      fit_scale(2.0, size(px(0.), px(20.)), IconFit::Contain),
+      (1.0, 1.0)
+    );
+  }
+}
@@ -0,0 +1,502 @@
+//! Gradient strokes for icons.
+
+use crate::svg::{hex_color, set_root_attribute, svg_number};
+use crate::{IconName, IconTransform, parse_view_box};
+use gpui::{
+  App, Global, Hsla, Image, ImageFormat, LinearColorStop, Pixels, Point, Rgba, SharedString, Size,
+  point,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+/// Id of the gradient injected into icon markup.
+const GRADIENT_ID: &str = "icon-gradient";
+
+/// ViewBox assumed for markup without one, matching Lucide icons.
+const DEFAULT_VIEW_BOX: [f32; 4] = [0.0, 0.0, 24.0, 24.0];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GradientShape {
+  /// Angle in degrees of a linear gradient spanning the whole viewBox.
+  Angle(f32),
+  Linear {
+    start: Point<f32>,
+    end: Point<f32>,
+  },
+  Radial {
+    center: Point<f32>,
+    radius: f32,
+  },
+}
+
+/// A linear or radial gradient that paints an icon's strokes and fills.
+///
+/// Coordinates are in icon space, the units of the icon's viewBox (`0..24` for Lucide
+/// icons), so a gradient looks the same at every size. Colors are given as gpui
+/// `LinearColorStop`s, in order.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui::{linear_color_stop, point, rgb};
+/// use gpui_lucide::IconGradient;
+///
+/// // Left to right across the icon, like `gpui::linear_gradient`.
+/// let sunset = IconGradient::angle(
+///     90.,
+///     linear_color_stop(rgb(0xfeca57), 0.),
+///     linear_color_stop(rgb(0xe94560), 1.),
+/// );
+///
+/// // Glowing from the center of a 24-unit icon.
+/// let glow = IconGradient::radial(point(12., 12.), 12.)
+///     .stop(rgb(0x48dbfb), 0.)
+///     .stop(rgb(0x5f27cd), 1.);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IconGradient {
+  shape: GradientShape,
+  stops: Vec<LinearColorStop>,
+}
+
+impl IconGradient {
+  /// Creates a linear gradient across the whole viewBox at `angle` degrees, the way
+  /// `gpui::linear_gradient` does: 0 runs bottom to top and 90 left to right.
+  pub fn angle(angle: f32, from: LinearColorStop, to: LinearColorStop) -> Self {
+    Self {
+      shape: GradientShape::Angle(angle),
+      stops: vec![from, to],
+    }
+  }
+
+  /// Creates a linear gradient from `start` to `end`, in viewBox units.
+  pub fn linear(start: Point<f32>, end: Point<f32>) -> Self {
+    Self {
+      shape: GradientShape::Linear { start, end },
+      stops: Vec::new(),
+    }
+  }
+
+  /// Creates a radial gradient around `center` with `radius`, in viewBox units.
+  pub fn radial(center: Point<f32>, radius: f32) -> Self {
+    Self {
+      shape: GradientShape::Radial { center, radius },
+      stops: Vec::new(),
+    }
+  }
+
+  /// Adds a color at `percentage` along the gradient, from 0 to 1.
+  pub fn stop(mut self, color: impl Into<Hsla>, percentage: f32) -> Self {
+    self.stops.push(LinearColorStop {
+      color: color.into(),
+      percentage,
+    });
+    self
+  }
+
+  /// Adds color stops, such as ones made with `gpui::linear_color_stop`.
+  pub fn stops(mut self, stops: impl IntoIterator<Item = LinearColorStop>) -> Self {
+    self.stops.extend(stops);
+    self
+  }
+
+  /// Returns the color of the first stop, for painters that only take one color.
+  pub(crate) fn first_color(&self) -> Option<Hsla> {
+    self.stops.first().map(|stop| stop.color)
+  }
+
+  /// Paints `currentColor` in SVG markup with this gradient.
+  ///
+  /// The gradient is defined right after the root tag and resolved against the
+  /// markup's viewBox.
+  pub fn apply(&self, markup: &str) -> String {
+    let Some(tag_start) = markup.find("<svg") else {
+      return markup.to_string();
+    };
+    let Some(tag_len) = markup[tag_start..].find('>') else {
+      return markup.to_string();
+    };
+    let tag_end = tag_start + tag_len + 1;
+    if markup[..tag_end].ends_with("/>") {
+      return markup.to_string();
+    }
+
+    let view_box = parse_view_box(markup).unwrap_or(DEFAULT_VIEW_BOX);
+    let paint = format!("url(#{})", GRADIENT_ID);
+    format!(
+      "{}{}{}",
+      markup[..tag_end].replace("currentColor", &paint),
+      self.defs(view_box),
+      markup[tag_end..].replace("currentColor", &paint)
+    )
+  }
+
+  /// Returns the `<defs>` element defining this gradient for an icon with `view_box`.
+  fn defs(&self, view_box: [f32; 4]) -> String {
+    let stops: String = self.stops.iter().map(stop_element).collect();
+    let attributes = match self.shape {
+      GradientShape::Angle(angle) => {
+        let (start, end) = angle_endpoints(angle, view_box);
+        linear_attributes(start, end)
+      }
+      GradientShape::Linear { start, end } => linear_attributes(start, end),
+      GradientShape::Radial { center, radius } => format!(
+        "cx=\"{}\" cy=\"{}\" r=\"{}\"",
+        svg_number(center.x),
+        svg_number(center.y),
+        svg_number(radius)
+      ),
+    };
+    let element = match self.shape {
+      GradientShape::Radial { .. } => "radialGradient",
+      _ => "linearGradient",
+    };
+    format!(
+      "<defs><{} id=\"{}\" gradientUnits=\"userSpaceOnUse\" {}>{}</{}></defs>",
+      element, GRADIENT_ID, attributes, stops, element
+    )
+  }
+
+  /// Returns the exact gradient parameters, for use as a cache key.
+  fn cache_key(&self) -> Vec<u32> {
+    let shape = match self.shape {
+      GradientShape::Angle(angle) => vec![0, angle.to_bits()],
+      GradientShape::Linear { start, end } => vec![
+        1,
+        start.x.to_bits(),
+        start.y.to_bits(),
+        end.x.to_bits(),
+        end.y.to_bits(),
+      ],
+      GradientShape::Radial { center, radius } => {
+        vec![2, center.x.to_bits(), center.y.to_bits(), radius.to_bits()]
+      }
+    };
+    let stops = self.stops.iter().flat_map(|stop| {
+      let color = stop.color;
+      [color.h, color.s, color.l, color.a, stop.percentage].map(f32::to_bits)
+    });
+    shape.into_iter().chain(stops).collect()
+  }
+}
+
+/// Returns the endpoints of a linear gradient at `angle` degrees that spans
+/// `view_box`, following CSS `linear-gradient` geometry.
+fn angle_endpoints(angle: f32, view_box: [f32; 4]) -> (Point<f32>, Point<f32>) {
+  let [x, y, width, height] = view_box;
+  let (sin, cos) = angle.to_radians().sin_cos();
+  let half = (width * sin.abs() + height * cos.abs()) / 2.0;
+  let center = point(x + width / 2.0, y + height / 2.0);
+  (
+    point(center.x - sin * half, center.y + cos * half),
+    point(center.x + sin * half, center.y - cos * half),
+  )
+}
+
+fn linear_attributes(start: Point<f32>, end: Point<f32>) -> String {
+  format!(
+    "x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"",
+    svg_number(start.x),
+    svg_number(start.y),
+    svg_number(end.x),
+    svg_number(end.y)
+  )
+}
+
+fn stop_element(stop: &LinearColorStop) -> String {
+  let rgba = Rgba::from(stop.color);
+  let opacity = if rgba.a < 1.0 {
+    format!(" stop-opacity=\"{}\"", svg_number(rgba.a))
+  } else {
+    String::new()
+  };
+  format!(
+    "<stop offset=\"{}\" stop-color=\"{}\"{}/>",
+    svg_number(stop.percentage.clamp(0.0, 1.0)),
+    hex_color(rgba),
+    opacity
+  )
+}
+
+/// Number of gradient variants kept before the least recently used one is dropped.
+const GRADIENT_CACHE_SIZE: usize = 256;
+
+/// Identifies a gradient variant of an icon.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct GradientKey {
+  path: SharedString,
+  gradient: Vec<u32>,
+  transform: Option<String>,
+  width: u32,
+  height: u32,
+}
+
+/// A cached gradient variant and when it was last used.
+struct CachedGradient {
+  image: Option<Arc<Image>>,
+  last_used: u64,
+}
+
+/// Gradient variants of icons, evicting the least recently used past
+/// [`GRADIENT_CACHE_SIZE`], so icons whose size or transform animates do not grow it
+/// without bound.
+#[derive(Default)]
+struct GradientCache {
+  images: HashMap<GradientKey, CachedGradient>,
+  /// Keys by the tick they were last used at, least recent first.
+  order: BTreeMap<u64, GradientKey>,
+  tick: u64,
+}
+
+impl Global for GradientCache {}
+
+impl GradientCache {
+  fn get(&mut self, key: &GradientKey) -> Option<Option<Arc<Image>>> {
+    let entry = self.images.get_mut(key)?;
+    self.tick += 1;
+    if let Some(key) = self.order.remove(&entry.last_used) {
+      self.order.insert(self.tick, key);
+    }
+    entry.last_used = self.tick;
+    Some(entry.image.clone())
+  }
+
+  fn insert(&mut self, key: GradientKey, image: Option<Arc<Image>>) {
+    self.tick += 1;
+    let entry = CachedGradient {
+      image,
+      last_used: self.tick,
+    };
+    if let Some(previous) = self.images.insert(key.clone(), entry) {
+      self.order.remove(&previous.last_used);
+    }
+    self.order.insert(self.tick, key);
+    while self.images.len() > GRADIENT_CACHE_SIZE {
+      let Some((_, oldest)) = self.order.pop_first() else {
+        break;
+      };
+      self.images.remove(&oldest);
+    }
+  }
+}
+
+/// Paths already warned about by [`warn_relative_gradient`].
+#[derive(Default)]
+struct GradientWarnings {
+  paths: HashSet<SharedString>,
+}
+
+impl Global for GradientWarnings {}
+
+/// Logs, once per path, that a gradient icon sized with relative lengths is rasterized
+/// at its viewBox size.
+fn warn_relative_gradient(path: &SharedString, cx: &mut App) {
+  if cx
+    .default_global::<GradientWarnings>()
+    .paths
+    .insert(path.clone())
+  {
+    log::warn!(
+      "the gradient image of `{}` is rasterized at its viewBox size and may look blurry: \
+       gradient icons need a predefined size or an absolute width and height",
+      path
+    );
+  }
+}
+
+/// Returns the SVG at `path` painted with `gradient` and transformed by `transform`,
+/// sized for `size`.
+///
+/// Variants are cached by path, gradient, transform and pixel size, so gpui renders
+/// each texture once while it stays in use. Returns `None` when the asset cannot be
+/// loaded.
+pub(crate) fn gradient_image(
+  path: &SharedString,
+  gradient: &IconGradient,
+  transform: &IconTransform,
+  view_box: Option<[f32; 4]>,
+  size: Size<Pixels>,
+  cx: &mut App,
+) -> Option<Arc<Image>> {
+  let view_box = view_box.unwrap_or(DEFAULT_VIEW_BOX);
+  let key = GradientKey {
+    path: path.clone(),
+    gradient: gradient.cache_key(),
+    transform: transform.svg_transform(view_box, size),
+    width: f32::from(size.width).round().max(0.0) as u32,
+    height: f32::from(size.height).round().max(0.0) as u32,
+  };
+  if let Some(image) = cx.default_global::<GradientCache>().get(&key) {
+    return image;
+  }
+  if key.width == 0 || key.height == 0 {
+    warn_relative_gradient(path, cx);
+  }
+
+  let markup = match IconName::from_path(path) {
+    Some(icon) => Some(icon.svg().to_string()),
+    None => cx
+      .asset_source()
+      .load(path)
+      .ok()
+      .flatten()
+      .and_then(|bytes| String::from_utf8(bytes.into_owned()).ok()),
+  };
+  let image = markup.map(|markup| {
+    let mut markup = gradient.apply(&markup);
+    if let Some(transform) = &key.transform {
+      markup = wrap_content(&markup, transform);
+    }
+    // gpui rasterizes SVG images from their intrinsic size, so match the layout size,
+    // and stretch like `svg()` does so fit modes apply the same way.
+    if key.width > 0 && key.height > 0 {
+      markup = set_root_attribute(&markup, "width", &key.width.to_string());
+      markup = set_root_attribute(&markup, "height", &key.height.to_string());
+      markup = set_root_attribute(&markup, "preserveAspectRatio", "none");
+    }
+    Arc::new(Image::from_bytes(ImageFormat::Svg, markup.into_bytes()))
+  });
+
+  cx.default_global::<GradientCache>()
+    .insert(key, image.clone());
+  image
+}
+
+/// Wraps the drawing inside the root `<svg>` in a group with `transform`, leaving
+/// `<defs>` in gradient space.
+fn wrap_content(markup: &str, transform: &str) -> String {
+  let start = match markup.find("</defs>") {
+    Some(index) => index + "</defs>".len(),
+    None => match markup
+      .find("<svg")
+      .and_then(|start| markup[start..].find('>').map(|end| start + end + 1))
+    {
+      Some(index) => index,
+      None => return markup.to_string(),
+    },
+  };
+  let Some(end) = markup.rfind("</svg>").filter(|end| *end >= start) else {
+    return markup.to_string();
+  };
+  format!(
+    "{}<g transform=\"{}\">{}</g>{}",
+    &markup[..start],
+    transform,
+    &markup[start..end],
+    &markup[end..]
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use gpui::{linear_color_stop, rgb};
+
+  fn sunset(angle: f32) -> IconGradient {
+    IconGradient::angle(
+      angle,
+      linear_color_stop(rgb(0xfeca57), 0.),
+      linear_color_stop(rgb(0xe94560), 1.),
+    )
+  }
+
+  #[test]
+  fn test_angle_endpoints_span_the_view_box() {
+    let view_box = [0.0, 0.0, 24.0, 24.0];
+    let (start, end) = angle_endpoints(90.0, view_box);
+    assert_eq!(
+      (svg_number(start.x), svg_number(start.y)),
+      ("0".into(), "12".into())
+    );
+    assert_eq!(
+      (svg_number(end.x), svg_number(end.y)),
+      ("24".into(), "12".into())
+    );
+
+    let (start, end) = angle_endpoints(0.0, view_box);
+    assert_eq!((start.y, end.y), (24.0, 0.0));
+  }
+
+  #[test]
+  fn test_apply_paints_current_color_with_the_gradient() {
+    let markup = sunset(90.0).apply(IconName::Heart.svg());
+
+    assert!(!markup.contains("currentColor"));
+    assert!(markup.contains("stroke=\"url(#icon-gradient)\""));
+    assert!(markup.contains(
+      "<linearGradient id=\"icon-gradient\" gradientUnits=\"userSpaceOnUse\" \
+       x1=\"0\" y1=\"12\" x2=\"24\" y2=\"12\">"
+    ));
+    assert!(markup.contains("<stop offset=\"0\" stop-color=\"#feca57\"/>"));
+    assert!(markup.contains("<stop offset=\"1\" stop-color=\"#e94560\"/>"));
+
+    let root_end = markup.find('>').unwrap();
+    assert!(markup[root_end + 1..].starts_with("<defs>"));
+  }
+
+  #[test]
+  fn test_apply_uses_the_view_box() {
+    let markup =
+      sunset(90.0).apply("<svg viewBox=\"0 0 64 16\"><path fill=\"currentColor\"/></svg>");
+    assert!(markup.contains("x1=\"0\" y1=\"8\" x2=\"64\" y2=\"8\""));
+    assert!(markup.contains("fill=\"url(#icon-gradient)\""));
+  }
+
+  #[test]
+  fn test_radial_gradient() {
+    let gradient = IconGradient::radial(point(12., 12.), 10.)
+      .stop(rgb(0x48dbfb), 0.)
+      .stop(gpui::hsla(0.0, 0.0, 0.0, 0.5), 1.);
+    let markup = gradient.apply(IconName::Circle.svg());
+
+    assert!(markup.contains("<radialGradient id=\"icon-gradient\" gradientUnits=\"userSpaceOnUse\" cx=\"12\" cy=\"12\" r=\"10\">"));
+    assert!(markup.contains("stop-opacity=\"0.5\""));
+  }
+
+  #[test]
+  fn test_wrap_content_keeps_defs_outside_the_group() {
+    let markup = sunset(90.0).apply("<svg viewBox=\"0 0 24 24\"><path d=\"M0 0\"/></svg>");
+    let wrapped = wrap_content(&markup, "rotate(90)");
+    assert!(wrapped.contains("</defs><g transform=\"rotate(90)\"><path d=\"M0 0\"/></g></svg>"));
+  }
+
+  #[test]
+  fn test_cache_evicts_least_recently_used() {
+    let key = |width: u32| GradientKey {
+      path: "icons/heart.svg".into(),
+      gradient: sunset(90.0).cache_key(),
+      transform: None,
+      width,
+      height: width,
+    };
+    let mut cache = GradientCache::default();
+    for width in 0..GRADIENT_CACHE_SIZE as u32 {
+      cache.insert(key(width), None);
+    }
+    assert!(cache.get(&key(0)).is_some());
+
+    cache.insert(key(1000), None);
+    assert_eq!(cache.images.len(), GRADIENT_CACHE_SIZE);
+    assert!(cache.get(&key(0)).is_some());
+    assert!(cache.get(&key(1)).is_none());
+    assert!(cache.get(&key(1000)).is_some());
+
+    cache.insert(key(1000), None);
+    assert_eq!(cache.images.len(), GRADIENT_CACHE_SIZE);
+    assert_eq!(cache.order.len(), GRADIENT_CACHE_SIZE);
+  }
+
+  #[test]
+  fn test_cache_key_follows_gradient_parameters() {
+    assert_eq!(sunset(90.0).cache_key(), sunset(90.0).cache_key());
+    assert_ne!(sunset(90.0).cache_key(), sunset(45.0).cache_key());
+    assert_ne!(
+      IconGradient::linear(point(0., 0.), point(24., 0.)).cache_key(),
+      IconGradient::linear(point(0., 0.), point(0., 24.)).cache_key()
+    );
+    assert_ne!(
+      sunset(90.0).cache_key(),
+      sunset(90.0).stop(rgb(0xffffff), 1.).cache_key()
+    );
+  }
+}
@@ -0,0 +1,217 @@
+//! Icon next to a text label.
+
+use crate::{Icon, IconNamed, IconSize};
+use gpui::{
+  App, Hsla, IntoElement, RenderOnce, SharedString, StyleRefinement, Styled, Window, div,
+  prelude::*, rems,
+};
+
+/// Gap between icon and label as a fraction of the icon size.
+const GAP: f32 = 0.375;
+
+/// Side of the label the icon is drawn on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IconPosition {
+  /// Before the label.
+  #[default]
+  Leading,
+  /// After the label.
+  Trailing,
+}
+
+/// Emphasis of an `IconLabel`'s text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LabelTone {
+  /// The inherited text color.
+  #[default]
+  Default,
+  /// Slightly faded, for supporting text.
+  Secondary,
+  /// Strongly faded, for disabled or placeholder-like text.
+  Muted,
+}
+
+impl LabelTone {
+  /// Returns the label color for a base text color.
+  pub fn apply(self, color: Hsla) -> Hsla {
+    match self {
+      Self::Default => color,
+      Self::Secondary => color.opacity(0.7),
+      Self::Muted => color.opacity(0.5),
+    }
+  }
+}
+
+/// An icon and a label laid out with consistent spacing, as in menu items, tabs and
+/// buttons.
+///
+/// By default the icon is as tall as the label's font size and the gap scales with
+/// it, including text sizes set on the label itself such as `.text_xs()`. `with_size`
+/// sets a predefined icon size, and the gap follows that instead.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{IconLabel, IconName, IconPosition, LabelTone};
+///
+/// IconLabel::new(IconName::Settings, "Settings")
+///     .position(IconPosition::Leading)
+///     .tone(LabelTone::Secondary)
+///     .truncate();
+/// ```
+#[derive(IntoElement)]
+pub struct IconLabel {
+  icon: Icon,
+  label: SharedString,
+  position: IconPosition,
+  tone: LabelTone,
+  size: Option<IconSize>,
+  color: Option<Hsla>,
+  truncate: bool,
+  custom_style: StyleRefinement,
+}
+
+impl IconLabel {
+  /// Creates a label with a leading icon.
+  pub fn new(icon: impl IconNamed, label: impl Into<SharedString>) -> Self {
+    Self::from_icon(Icon::new(icon), label)
+  }
+
+  /// Creates a label with a configured `Icon`, such as one with a transform.
+  pub fn from_icon(icon: Icon, label: impl Into<SharedString>) -> Self {
+    Self {
+      icon,
+      label: label.into(),
+      position: IconPosition::default(),
+      tone: LabelTone::default(),
+      size: None,
+      color: None,
+      truncate: false,
+      custom_style: StyleRefinement::default(),
+    }
+  }
+
+  /// Sets the side of the label the icon is drawn on.
+  pub fn position(mut self, position: IconPosition) -> Self {
+    self.position = position;
+    self
+  }
+
+  /// Draws the icon after the label.
+  pub fn trailing(self) -> Self {
+    self.position(IconPosition::Trailing)
+  }
+
+  /// Sets the emphasis of the label text.
+  pub fn tone(mut self, tone: LabelTone) -> Self {
+    self.tone = tone;
+    self
+  }
+
+  /// Sets the icon size and the gap that goes with it.
+  pub fn with_size(mut self, size: IconSize) -> Self {
+    self.size = Some(size);
+    self
+  }
+
+  /// Sets the color of the label, and of the icon unless it was created with a color
+  /// of its own.
+  pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+    self.color = Some(color.into());
+    self
+  }
+
+  /// Cuts the label off with an ellipsis when it does not fit.
+  pub fn truncate(mut self) -> Self {
+    self.truncate = true;
+    self
+  }
+}
+
+impl Styled for IconLabel {
+  fn style(&mut self) -> &mut StyleRefinement {
+    &mut self.custom_style
+  }
+}
+
+impl RenderOnce for IconLabel {
+  fn render(self, window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    // Text styles set on the label apply to its children, so they win over inherited ones.
+    let text_style = window.text_style();
+    let font_size = self
+      .custom_style
+      .text
+      .font_size
+      .unwrap_or(text_style.font_size)
+      .to_pixels(window.rem_size());
+    let color = self
+      .color
+      .or(self.custom_style.text.color)
+      .unwrap_or(text_style.color);
+    let gap = match self.size {
+      Some(size) => rems(size.to_rems() * GAP).to_pixels(window.rem_size()),
+      None => font_size * GAP,
+    };
+
+    let has_own_color = self.icon.color.is_some() || self.icon.color_token.is_some();
+    let icon = self
+      .icon
+      .when(!has_own_color, |icon| icon.color(color))
+      .when_some(self.size, |icon, size| icon.with_size(size));
+    let label = div()
+      .text_color(self.tone.apply(color))
+      .when(self.truncate, |label| label.min_w_0().truncate())
+      .child(self.label);
+
+    let mut base = div();
+    *base.style() = self.custom_style;
+
+    base
+      .flex()
+      .flex_row()
+      .items_center()
+      .gap(gap)
+      .map(|this| match self.position {
+        IconPosition::Leading => this.child(icon).child(label),
+        IconPosition::Trailing => this.child(label).child(icon),
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::IconName;
+  use gpui::hsla;
+
+  #[test]
+  fn test_defaults() {
+    let label = IconLabel::new(IconName::Settings, "Settings");
+    assert_eq!(label.label.as_ref(), "Settings");
+    assert_eq!(label.icon.path.as_ref(), "icons/settings.svg");
+    assert_eq!(label.position, IconPosition::Leading);
+    assert_eq!(label.tone, LabelTone::Default);
+    assert!(!label.truncate);
+  }
+
+  #[test]
+  fn test_builder() {
+    let label = IconLabel::new(IconName::ChevronRight, "More")
+      .trailing()
+      .tone(LabelTone::Muted)
+      .with_size(IconSize::Small)
+      .truncate();
+    assert_eq!(label.position, IconPosition::Trailing);
+    assert_eq!(label.tone, LabelTone::Muted);
+    assert_eq!(label.size, Some(IconSize::Small));
+    assert!(label.truncate);
+  }
+
+  #[test]
+  fn test_tones_fade_the_text_color() {
+    let color = hsla(0.0, 0.0, 1.0, 1.0);
+    assert_eq!(LabelTone::Default.apply(color), color);
+    assert_eq!(LabelTone::Secondary.apply(color).a, 0.7);
+    assert_eq!(LabelTone::Muted.apply(color).a, 0.5);
+  }
+}
@@ -0,0 +1,677 @@
+//! Searchable grid for choosing an icon.
+
+use crate::search::{ParseQueryError, Query, SearchIndex};
+use crate::{Icon, IconName, IconNamed, IconPalette, IconSize, IconTransform, icon_palette};
+use gpui::{
+  App, Context, Div, ElementId, EventEmitter, FocusHandle, Focusable, Hsla, IntoElement,
+  KeyBinding, MouseButton, Pixels, Render, ScrollStrategy, SharedString, Stateful,
+  UniformListScrollHandle, Window, actions, div, prelude::*, px, uniform_list,
+};
+use std::rc::Rc;
+
+actions!(
+  icon_picker,
+  [
+    SelectPreviousIcon,
+    SelectNextIcon,
+    SelectIconAbove,
+    SelectIconBelow,
+    ConfirmIcon
+  ]
+);
+
+/// Key context set on the picker, for scoping key bindings.
+pub const ICON_PICKER_CONTEXT: &str = "IconPicker";
+
+/// Binds the arrow keys and enter to the picker actions.
+pub fn bind_icon_picker_keys(cx: &mut App) {
+  let context = Some(ICON_PICKER_CONTEXT);
+  cx.bind_keys([
+    KeyBinding::new("left", SelectPreviousIcon, context),
+    KeyBinding::new("right", SelectNextIcon, context),
+    KeyBinding::new("up", SelectIconAbove, context),
+    KeyBinding::new("down", SelectIconBelow, context),
+    KeyBinding::new("enter", ConfirmIcon, context),
+  ]);
+}
+
+/// Events emitted by an [`IconPicker`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum IconPickerEvent<I> {
+  /// An icon was chosen with a click or the confirm action.
+  Selected(I),
+  /// The hovered or keyboard-selected icon changed.
+  Highlighted(Option<I>),
+}
+
+/// Colors and metrics of an [`IconPicker`].
+///
+/// Colors left as `None` follow the [`IconTheme`](crate::IconTheme) palette of the
+/// window's appearance: cells are transparent, tinted with the default icon color on
+/// hover and with the accent color when selected, and labels use the muted color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconPickerStyle {
+  /// Width and height of each cell.
+  pub cell_size: Pixels,
+  /// Space between cells.
+  pub gap: Pixels,
+  /// Cell background.
+  pub cell: Option<Hsla>,
+  /// Cell background while hovered.
+  pub cell_hover: Option<Hsla>,
+  /// Cell background of the keyboard selection.
+  pub cell_selected: Option<Hsla>,
+  /// Color of icon names and section titles.
+  pub label: Option<Hsla>,
+}
+
+impl Default for IconPickerStyle {
+  fn default() -> Self {
+    Self {
+      cell_size: px(72.0),
+      gap: px(8.0),
+      cell: None,
+      cell_hover: None,
+      cell_selected: None,
+      label: None,
+    }
+  }
+}
+
+impl IconPickerStyle {
+  fn colors(&self, palette: &IconPalette) -> PickerColors {
+    PickerColors {
+      cell: self.cell.unwrap_or_else(Hsla::transparent_black),
+      cell_hover: self
+        .cell_hover
+        .unwrap_or_else(|| palette.default.opacity(0.08)),
+      cell_selected: self
+        .cell_selected
+        .unwrap_or_else(|| palette.accent.opacity(0.2)),
+      label: self.label.unwrap_or(palette.muted),
+    }
+  }
+}
+
+/// Colors of an [`IconPickerStyle`], resolved for one frame.
+#[derive(Clone, Copy)]
+struct PickerColors {
+  cell: Hsla,
+  cell_hover: Hsla,
+  cell_selected: Hsla,
+  label: Hsla,
+}
+
+/// A virtualized grid of icons with search, favorites and recently used icons.
+///
+/// The picker works with any `IconNamed` icon set and defaults to the Lucide icons.
+/// It has no text field of its own: feed the query from any input with
+/// [`IconPicker::set_query`]. Queries use the [`Query`] grammar and matches are ranked
+/// with a [`SearchIndex`], so typos and partial words still find icons. While the query
+/// is empty, favorite and recent icons are listed above the grid.
+///
+/// Subscribe to [`IconPickerEvent`] to react to the selection. Keyboard navigation uses
+/// the actions in the `icon_picker` namespace; [`bind_icon_picker_keys`] binds the
+/// default keys. The actions only reach the picker while it is focused: it takes focus
+/// when clicked, and [`IconPicker::focus`] moves focus to it, for example when the
+/// search input is submitted.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{IconPicker, IconPickerEvent};
+///
+/// let picker = cx.new(|cx| IconPicker::lucide(cx));
+/// cx.subscribe(&picker, |this, _, event, cx| {
+///     if let IconPickerEvent::Selected(icon) = event {
+///         this.folder_icon = *icon;
+///         cx.notify();
+///     }
+/// })
+/// .detach();
+/// ```
+pub struct IconPicker<I: IconNamed + Clone + PartialEq + 'static = IconName> {
+  focus_handle: FocusHandle,
+  index: SearchIndex<I>,
+  filter: Option<Rc<dyn Fn(&I) -> bool>>,
+  query: SharedString,
+  parsed_query: Query,
+  query_error: Option<ParseQueryError>,
+  matches: Vec<I>,
+  favorites: Vec<I>,
+  recent: Vec<I>,
+  max_recent: usize,
+  selected: Option<usize>,
+  hovered: Option<I>,
+  columns: usize,
+  scroll_handle: UniformListScrollHandle,
+  icon_color: Option<Hsla>,
+  icon_size: IconSize,
+  icon_transform: IconTransform,
+  style: IconPickerStyle,
+}
+
+impl IconPicker<IconName> {
+  /// Creates a picker over every Lucide icon, searchable by name, alias, tag and
+  /// category.
+  pub fn lucide(cx: &mut Context<Self>) -> Self {
+    Self::with_index(SearchIndex::lucide(), cx)
+  }
+}
+
+impl<I: IconNamed + Clone + PartialEq + 'static> IconPicker<I> {
+  /// Creates a picker over the given icon set, searchable by name.
+  pub fn new(icons: impl IntoIterator<Item = I>, cx: &mut Context<Self>) -> Self {
+    Self::with_index(SearchIndex::from_icons(icons), cx)
+  }
+
+  /// Creates a picker over the icons of `index`, searched with its keywords.
+  pub fn with_index(index: SearchIndex<I>, cx: &mut Context<Self>) -> Self {
+    Self {
+      focus_handle: cx.focus_handle(),
+      matches: index.icons().cloned().collect(),
+      index,
+      filter: None,
+      query: SharedString::default(),
+      parsed_query: Query::default(),
+      query_error: None,
+      favorites: Vec::new(),
+      recent: Vec::new(),
+      max_recent: 8,
+      selected: None,
+      hovered: None,
+      columns: 8,
+      scroll_handle: UniformListScrollHandle::new(),
+      icon_color: None,
+      icon_size: IconSize::Large,
+      icon_transform: IconTransform::default(),
+      style: IconPickerStyle::default(),
+    }
+  }
+
+  /// Sets the search query. Matches are ordered best first.
+  ///
+  /// A query that fails to parse keeps the previous matches; the error is available
+  /// from [`IconPicker::query_error`].
+  pub fn set_query(&mut self, query: impl Into<SharedString>, cx: &mut Context<Self>) {
+    let query = query.into();
+    if query == self.query {
+      return;
+    }
+    self.query = query;
+    match Query::parse(&self.query) {
+      Ok(parsed) => {
+        self.parsed_query = parsed;
+        self.query_error = None;
+        self.refresh(cx);
+      }
+      Err(error) => {
+        self.query_error = Some(error);
+        cx.notify();
+      }
+    }
+  }
+
+  /// Returns the current search query.
+  pub fn query(&self) -> &str {
+    &self.query
+  }
+
+  /// Returns why the current query could not be parsed, if it could not.
+  pub fn query_error(&self) -> Option<&ParseQueryError> {
+    self.query_error.as_ref()
+  }
+
+  /// Only shows icons for which `filter` returns true.
+  pub fn set_filter(&mut self, filter: impl Fn(&I) -> bool + 'static, cx: &mut Context<Self>) {
+    self.filter = Some(Rc::new(filter));
+    self.refresh(cx);
+  }
+
+  /// Removes the filter set with [`IconPicker::set_filter`].
+  pub fn clear_filter(&mut self, cx: &mut Context<Self>) {
+    self.filter = None;
+    self.refresh(cx);
+  }
+
+  /// Replaces the favorite icons.
+  pub fn set_favorites(&mut self, favorites: Vec<I>, cx: &mut Context<Self>) {
+    self.favorites = favorites;
+    cx.notify();
+  }
+
+  /// Returns the favorite icons.
+  pub fn favorites(&self) -> &[I] {
+    &self.favorites
+  }
+
+  /// Adds the icon to the favorites, or removes it when already there.
+  pub fn toggle_favorite(&mut self, icon: I, cx: &mut Context<Self>) {
+    if let Some(index) = self.favorites.iter().position(|favorite| *favorite == icon) {
+      self.favorites.remove(index);
+    } else {
+      self.favorites.push(icon);
+    }
+    cx.notify();
+  }
+
+  /// Replaces the recently selected icons, most recent first.
+  pub fn set_recent(&mut self, mut recent: Vec<I>, cx: &mut Context<Self>) {
+    recent.truncate(self.max_recent);
+    self.recent = recent;
+    cx.notify();
+  }
+
+  /// Returns the recently selected icons, most recent first.
+  pub fn recent(&self) -> &[I] {
+    &self.recent
+  }
+
+  /// Sets how many recent icons are kept. Defaults to 8.
+  pub fn set_max_recent(&mut self, max_recent: usize, cx: &mut Context<Self>) {
+    self.max_recent = max_recent;
+    self.recent.truncate(max_recent);
+    cx.notify();
+  }
+
+  /// Sets the number of icons per row. Defaults to 8.
+  pub fn set_columns(&mut self, columns: usize, cx: &mut Context<Self>) {
+    let columns = columns.max(1);
+    if columns != self.columns {
+      self.columns = columns;
+      cx.notify();
+    }
+  }
+
+  /// Sets the color of the icons. Defaults to the text color.
+  pub fn set_icon_color(&mut self, color: impl Into<Hsla>, cx: &mut Context<Self>) {
+    self.icon_color = Some(color.into());
+    cx.notify();
+  }
+
+  /// Sets the size of the icons. Defaults to `IconSize::Large`.
+  pub fn set_icon_size(&mut self, size: IconSize, cx: &mut Context<Self>) {
+    self.icon_size = size;
+    cx.notify();
+  }
+
+  /// Sets the transform applied to every icon.
+  pub fn set_icon_transform(&mut self, transform: IconTransform, cx: &mut Context<Self>) {
+    self.icon_transform = transform;
+    cx.notify();
+  }
+
+  /// Sets the colors and metrics of the picker.
+  pub fn set_style(&mut self, style: IconPickerStyle, cx: &mut Context<Self>) {
+    self.style = style;
+    cx.notify();
+  }
+
+  /// Returns the icons matching the query and filter, in display order.
+  pub fn matches(&self) -> &[I] {
+    &self.matches
+  }
+
+  /// Returns the icon selected with the keyboard.
+  pub fn selected_icon(&self) -> Option<&I> {
+    self.selected.and_then(|index| self.matches.get(index))
+  }
+
+  /// Focuses the picker, so the keyboard navigation actions reach it.
+  pub fn focus(&self, window: &mut Window, cx: &mut App) {
+    window.focus(&self.focus_handle, cx);
+  }
+
+  /// Selects an icon, records it as recent and emits [`IconPickerEvent::Selected`].
+  pub fn select(&mut self, icon: I, cx: &mut Context<Self>) {
+    push_recent(&mut self.recent, icon.clone(), self.max_recent);
+    cx.emit(IconPickerEvent::Selected(icon));
+    cx.notify();
+  }
+
+  fn refresh(&mut self, cx: &mut Context<Self>) {
+    self.matches = self
+      .index
+      .search(&self.parsed_query)
+      .into_iter()
+      .map(|result| result.icon)
+      .filter(|icon| self.filter.as_ref().is_none_or(|filter| filter(icon)))
+      .cloned()
+      .collect();
+    self.selected = initial_selection(self.parsed_query.is_empty(), self.matches.len());
+    self.scroll_handle.scroll_to_item(0, ScrollStrategy::Top);
+    cx.notify();
+  }
+
+  fn move_selection(&mut self, delta: isize, cx: &mut Context<Self>) {
+    self.selected = move_selection(self.selected, delta, self.matches.len());
+    if let Some(index) = self.selected {
+      self
+        .scroll_handle
+        .scroll_to_item(index / self.columns, ScrollStrategy::Center);
+    }
+    cx.emit(IconPickerEvent::Highlighted(self.selected_icon().cloned()));
+    cx.notify();
+  }
+
+  fn select_previous(&mut self, _: &SelectPreviousIcon, _: &mut Window, cx: &mut Context<Self>) {
+    self.move_selection(-1, cx);
+  }
+
+  fn select_next(&mut self, _: &SelectNextIcon, _: &mut Window, cx: &mut Context<Self>) {
+    self.move_selection(1, cx);
+  }
+
+  fn select_above(&mut self, _: &SelectIconAbove, _: &mut Window, cx: &mut Context<Self>) {
+    self.move_selection(-(self.columns as isize), cx);
+  }
+
+  fn select_below(&mut self, _: &SelectIconBelow, _: &mut Window, cx: &mut Context<Self>) {
+    self.move_selection(self.columns as isize, cx);
+  }
+
+  fn confirm(&mut self, _: &ConfirmIcon, _: &mut Window, cx: &mut Context<Self>) {
+    if let Some(icon) = self.selected_icon().cloned() {
+      self.select(icon, cx);
+    }
+  }
+
+  fn render_cell(
+    &self,
+    id: ElementId,
+    icon: I,
+    is_selected: bool,
+    colors: PickerColors,
+    cx: &mut Context<Self>,
+  ) -> Stateful<Div> {
+    let style = self.style;
+    let hovered = icon.clone();
+    let clicked = icon.clone();
+
+    div()
+      .id(id)
+      .w(style.cell_size)
+      .h(style.cell_size)
+      .flex_none()
+      .flex()
+      .flex_col()
+      .items_center()
+      .justify_center()
+      .gap_1()
+      .rounded_lg()
+      .cursor_pointer()
+      .bg(if is_selected {
+        colors.cell_selected
+      } else {
+        colors.cell
+      })
+      .hover(move |s| s.bg(colors.cell_hover))
+      .on_hover(cx.listener(move |this, is_hovered: &bool, _, cx| {
+        if let Some(highlighted) = update_hovered(&mut this.hovered, &hovered, *is_hovered) {
+          cx.emit(IconPickerEvent::Highlighted(highlighted));
+        }
+      }))
+      .on_click(cx.listener(move |this, _, _, cx| {
+        this.select(clicked.clone(), cx);
+      }))
+      .child(
+        Icon::new(icon.clone())
+          .with_size(self.icon_size)
+          .transform(self.icon_transform)
+          .when_some(self.icon_color, |this, color| this.color(color)),
+      )
+      .child(
+        div()
+          .text_xs()
+          .text_color(colors.label)
+          .max_w_full()
+          .overflow_hidden()
+          .child(truncate_label(icon.name(), 10)),
+      )
+  }
+
+  fn render_section(
+    &self,
+    title: &'static str,
+    icons: &[I],
+    colors: PickerColors,
+    cx: &mut Context<Self>,
+  ) -> Div {
+    let style = self.style;
+    div()
+      .flex()
+      .flex_col()
+      .gap_2()
+      .px_4()
+      .pb_4()
+      .child(div().text_xs().text_color(colors.label).child(title))
+      .child(
+        div()
+          .flex()
+          .flex_wrap()
+          .gap(style.gap)
+          .children(icons.iter().enumerate().map(|(index, icon)| {
+            let id = ElementId::NamedInteger(title.into(), index as u64);
+            self.render_cell(id, icon.clone(), false, colors, cx)
+          })),
+      )
+  }
+}
+
+impl<I: IconNamed + Clone + PartialEq + 'static> EventEmitter<IconPickerEvent<I>>
+  for IconPicker<I>
+{
+}
+
+impl<I: IconNamed + Clone + PartialEq + 'static> Focusable for IconPicker<I> {
+  fn focus_handle(&self, _: &App) -> FocusHandle {
+    self.focus_handle.clone()
+  }
+}
+
+impl<I: IconNamed + Clone + PartialEq + 'static> Render for IconPicker<I> {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    let style = self.style;
+    let colors = style.colors(&icon_palette(window, cx));
+    let columns = self.columns;
+    let rows = self.matches.len().div_ceil(columns);
+    let show_sections = self.parsed_query.is_empty();
+    let favorites = self.favorites.clone();
+    let recent = self.recent.clone();
+
+    div()
+      .key_context(ICON_PICKER_CONTEXT)
+      .track_focus(&self.focus_handle)
+      .on_action(cx.listener(Self::select_previous))
+      .on_action(cx.listener(Self::select_next))
+      .on_action(cx.listener(Self::select_above))
+      .on_action(cx.listener(Self::select_below))
+      .on_action(cx.listener(Self::confirm))
+      .on_mouse_down(
+        MouseButton::Left,
+        cx.listener(|this, _, window, cx| this.focus(window, cx)),
+      )
+      .size_full()
+      .flex()
+      .flex_col()
+      .pt_4()
+      .when(show_sections && !favorites.is_empty(), |this| {
+        this.child(self.render_section("Favorites", &favorites, colors, cx))
+      })
+      .when(show_sections && !recent.is_empty(), |this| {
+        this.child(self.render_section("Recent", &recent, colors, cx))
+      })
+      .child(
+        uniform_list(
+          "icon-picker-grid",
+          rows,
+          cx.processor(move |this, range: std::ops::Range<usize>, _, cx| {
+            range
+              .map(|row| {
+                let start = row * columns;
+                let end = (start + columns).min(this.matches.len());
+
+                div()
+                  .id(row)
+                  .h(style.cell_size + style.gap)
+                  .px_4()
+                  .flex()
+                  .gap(style.gap)
+                  .children((start..end).map(|index| {
+                    let icon = this.matches[index].clone();
+                    let is_selected = this.selected == Some(index);
+                    this.render_cell(index.into(), icon, is_selected, colors, cx)
+                  }))
+              })
+              .collect()
+          }),
+        )
+        .track_scroll(self.scroll_handle.clone())
+        .flex_1(),
+      )
+  }
+}
+
+/// Selects the best match while searching, and nothing while browsing.
+fn initial_selection(query_is_empty: bool, len: usize) -> Option<usize> {
+  (!query_is_empty && len > 0).then_some(0)
+}
+
+fn move_selection(selected: Option<usize>, delta: isize, len: usize) -> Option<usize> {
+  if len == 0 {
+    return None;
+  }
+  match selected {
+    None if delta < 0 => Some(len - 1),
+    None => Some(0),
+    Some(index) => Some((index as isize + delta).clamp(0, len as isize - 1) as usize),
+  }
+}
+
+/// Records a hover change and returns the highlight to emit, if any.
+///
+/// Leaving a cell only clears the highlight when no other cell was entered since, as
+/// the next cell's enter can arrive before the previous cell's leave.
+fn update_hovered<I: Clone + PartialEq>(
+  hovered: &mut Option<I>,
+  icon: &I,
+  is_hovered: bool,
+) -> Option<Option<I>> {
+  if is_hovered {
+    *hovered = Some(icon.clone());
+    Some(Some(icon.clone()))
+  } else if hovered.as_ref() == Some(icon) {
+    *hovered = None;
+    Some(None)
+  } else {
+    None
+  }
+}
+
+fn push_recent<I: PartialEq>(recent: &mut Vec<I>, icon: I, max_recent: usize) {
+  recent.retain(|existing| *existing != icon);
+  recent.insert(0, icon);
+  recent.truncate(max_recent);
+}
+
+fn truncate_label(name: &str, max_chars: usize) -> String {
+  if name.chars().count() > max_chars {
+    let head: String = name.chars().take(max_chars - 2).collect();
+    format!("{}...", head)
+  } else {
+    name.to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_move_selection_starts_at_edges() {
+    assert_eq!(move_selection(None, 1, 10), Some(0));
+    assert_eq!(move_selection(None, -1, 10), Some(9));
+    assert_eq!(move_selection(None, 1, 0), None);
+  }
+
+  #[test]
+  fn test_move_selection_clamps() {
+    assert_eq!(move_selection(Some(2), 8, 10), Some(9));
+    assert_eq!(move_selection(Some(2), -8, 10), Some(0));
+    assert_eq!(move_selection(Some(2), 1, 10), Some(3));
+  }
+
+  #[test]
+  fn test_move_selection_by_rows() {
+    let columns = 8;
+    assert_eq!(move_selection(Some(3), columns, 20), Some(11));
+    assert_eq!(move_selection(Some(11), columns, 20), Some(19));
+    assert_eq!(move_selection(Some(19), columns, 20), Some(19));
+    assert_eq!(move_selection(Some(11), -columns, 20), Some(3));
+    assert_eq!(move_selection(None, columns, 20), Some(0));
+  }
+
+  #[test]
+  fn test_initial_selection_follows_query() {
+    assert_eq!(initial_selection(false, 5), Some(0));
+    assert_eq!(initial_selection(false, 0), None);
+    assert_eq!(initial_selection(true, 5), None);
+  }
+
+  #[test]
+  fn test_hover_leave_keeps_newer_highlight() {
+    let mut hovered = None;
+    assert_eq!(
+      update_hovered(&mut hovered, &IconName::Heart, true),
+      Some(Some(IconName::Heart))
+    );
+    assert_eq!(
+      update_hovered(&mut hovered, &IconName::Star, true),
+      Some(Some(IconName::Star))
+    );
+    assert_eq!(update_hovered(&mut hovered, &IconName::Heart, false), None);
+    assert_eq!(hovered, Some(IconName::Star));
+    assert_eq!(
+      update_hovered(&mut hovered, &IconName::Star, false),
+      Some(None)
+    );
+    assert_eq!(hovered, None);
+  }
+
+  #[test]
+  fn test_style_colors_follow_palette() {
+    let palette = IconPalette::light();
+    let colors = IconPickerStyle::default().colors(&palette);
+    assert_eq!(colors.cell, Hsla::transparent_black());
+    assert_eq!(colors.cell_hover, palette.default.opacity(0.08));
+    assert_eq!(colors.cell_selected, palette.accent.opacity(0.2));
+    assert_eq!(colors.label, palette.muted);
+
+    let label = gpui::rgb(0xe94560).into();
+    let style = IconPickerStyle {
+      label: Some(label),
+      ..IconPickerStyle::default()
+    };
+    assert_eq!(style.colors(&IconPalette::dark()).label, label);
+  }
+
+  #[test]
+  fn test_push_recent_moves_icon_to_front() {
+    let mut recent = vec![IconName::Heart, IconName::Star];
+    push_recent(&mut recent, IconName::Star, 8);
+    assert_eq!(recent, vec![IconName::Star, IconName::Heart]);
+  }
+
+  #[test]
+  fn test_push_recent_respects_limit() {
+    let mut recent = vec![IconName::Heart, IconName::Star];
+    push_recent(&mut recent, IconName::Bell, 2);
+    assert_eq!(recent, vec![IconName::Bell, IconName::Heart]);
+  }
+
+  #[test]
+  fn test_truncate_label() {
+    assert_eq!(truncate_label("heart", 10), "heart");
+    assert_eq!(truncate_label("arrow-down-wide-narrow", 10), "arrow-do...");
+  }
+}
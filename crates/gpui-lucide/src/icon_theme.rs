@@ -0,0 +1,187 @@
+//! Semantic icon colors that follow the window appearance.
+
+use gpui::{App, Global, Hsla, Window, WindowAppearance, rgb};
+
+/// A semantic icon color, resolved from the [`IconTheme`] when the icon is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IconColor {
+  /// The regular icon color.
+  #[default]
+  Default,
+  /// Less prominent icons, such as hints and secondary actions.
+  Muted,
+  /// Highlighted or selected icons.
+  Accent,
+  /// Completed or healthy states.
+  Success,
+  /// States that need attention.
+  Warning,
+  /// Errors and destructive actions.
+  Danger,
+  /// Icons of disabled controls.
+  Disabled,
+}
+
+/// Colors of every [`IconColor`] for one appearance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconPalette {
+  pub default: Hsla,
+  pub muted: Hsla,
+  pub accent: Hsla,
+  pub success: Hsla,
+  pub warning: Hsla,
+  pub danger: Hsla,
+  pub disabled: Hsla,
+}
+
+impl IconPalette {
+  /// Colors for light backgrounds.
+  pub fn light() -> Self {
+    Self {
+      default: rgb(0x171717).into(),
+      muted: rgb(0x737373).into(),
+      accent: rgb(0x2563eb).into(),
+      success: rgb(0x16a34a).into(),
+      warning: rgb(0xd97706).into(),
+      danger: rgb(0xdc2626).into(),
+      disabled: rgb(0xa3a3a3).into(),
+    }
+  }
+
+  /// Colors for dark backgrounds.
+  pub fn dark() -> Self {
+    Self {
+      default: rgb(0xe5e5e5).into(),
+      muted: rgb(0xa3a3a3).into(),
+      accent: rgb(0x60a5fa).into(),
+      success: rgb(0x4ade80).into(),
+      warning: rgb(0xfbbf24).into(),
+      danger: rgb(0xf87171).into(),
+      disabled: rgb(0x525252).into(),
+    }
+  }
+
+  /// Returns the color of `token`.
+  pub fn color(&self, token: IconColor) -> Hsla {
+    match token {
+      IconColor::Default => self.default,
+      IconColor::Muted => self.muted,
+      IconColor::Accent => self.accent,
+      IconColor::Success => self.success,
+      IconColor::Warning => self.warning,
+      IconColor::Danger => self.danger,
+      IconColor::Disabled => self.disabled,
+    }
+  }
+}
+
+/// Light and dark palettes for semantic icon colors.
+///
+/// Set it once with [`set_icon_theme`]; icons using [`Icon::color_token`] pick the
+/// palette matching their window's appearance every time they are drawn, so they
+/// follow system light and dark mode switches.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{Icon, IconColor, IconName, IconTheme, set_icon_theme};
+///
+/// let mut theme = IconTheme::default();
+/// theme.dark.accent = gpui::rgb(0xe94560).into();
+/// set_icon_theme(cx, theme);
+///
+/// Icon::new(IconName::Trash).color_token(IconColor::Danger);
+/// ```
+///
+/// [`Icon::color_token`]: crate::Icon::color_token
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconTheme {
+  /// Palette for light appearances.
+  pub light: IconPalette,
+  /// Palette for dark appearances.
+  pub dark: IconPalette,
+}
+
+impl Default for IconTheme {
+  fn default() -> Self {
+    Self {
+      light: IconPalette::light(),
+      dark: IconPalette::dark(),
+    }
+  }
+}
+
+impl IconTheme {
+  /// Returns the palette for `appearance`.
+  pub fn palette(&self, appearance: WindowAppearance) -> &IconPalette {
+    match appearance {
+      WindowAppearance::Light | WindowAppearance::VibrantLight => &self.light,
+      WindowAppearance::Dark | WindowAppearance::VibrantDark => &self.dark,
+    }
+  }
+
+  /// Returns the color of `token` for `appearance`.
+  pub fn color(&self, token: IconColor, appearance: WindowAppearance) -> Hsla {
+    self.palette(appearance).color(token)
+  }
+}
+
+impl Global for IconTheme {}
+
+/// Sets the palettes semantic icon colors resolve from.
+pub fn set_icon_theme(cx: &mut App, theme: IconTheme) {
+  cx.set_global(theme);
+}
+
+/// Returns the color of `token` for the appearance of `window`.
+pub fn icon_color(token: IconColor, window: &Window, cx: &App) -> Hsla {
+  icon_palette(window, cx).color(token)
+}
+
+/// Returns the palette of the current [`IconTheme`] for the appearance of `window`.
+pub(crate) fn icon_palette(window: &Window, cx: &App) -> IconPalette {
+  let appearance = window.appearance();
+  match cx.try_global::<IconTheme>() {
+    Some(theme) => *theme.palette(appearance),
+    None => *IconTheme::default().palette(appearance),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_palette_follows_appearance() {
+    let theme = IconTheme::default();
+    assert_eq!(
+      theme.color(IconColor::Danger, WindowAppearance::Light),
+      IconPalette::light().danger
+    );
+    assert_eq!(
+      theme.color(IconColor::Danger, WindowAppearance::VibrantDark),
+      IconPalette::dark().danger
+    );
+    assert_eq!(
+      theme.palette(WindowAppearance::VibrantLight),
+      &IconPalette::light()
+    );
+  }
+
+  #[test]
+  fn test_every_token_has_a_color() {
+    let palette = IconPalette::dark();
+    let tokens = [
+      (IconColor::Default, palette.default),
+      (IconColor::Muted, palette.muted),
+      (IconColor::Accent, palette.accent),
+      (IconColor::Success, palette.success),
+      (IconColor::Warning, palette.warning),
+      (IconColor::Danger, palette.danger),
+      (IconColor::Disabled, palette.disabled),
+    ];
+    for (token, color) in tokens {
+      assert_eq!(palette.color(token), color);
+    }
+  }
+}
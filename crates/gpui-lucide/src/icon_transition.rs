@@ -0,0 +1,235 @@
+//! Animated swaps between icons.
+
+use crate::Icon;
+use gpui::{
+  Animation, AnimationExt, App, ElementId, IntoElement, RenderOnce, Styled, Window, div,
+  ease_in_out, prelude::*, radians,
+};
+use std::f32::consts::FRAC_PI_2;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// How an `IconTransition` animates from one icon to the next.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TransitionStyle {
+  /// Fades the old icon out while the new one fades in.
+  #[default]
+  Crossfade,
+  /// Shrinks the old icon away and grows the new one in its place.
+  ScaleSwap,
+  /// Turns the old icon out a quarter turn while the new one turns in.
+  RotateSwap,
+}
+
+impl TransitionStyle {
+  /// Applies the style to the outgoing icon at `t` (0 to 1).
+  fn exit(self, icon: Icon, t: f32) -> Icon {
+    let icon = icon.opacity(1.0 - t);
+    match self {
+      Self::Crossfade => icon,
+      Self::ScaleSwap => icon.scale(1.0 - t),
+      Self::RotateSwap => icon.rotate(radians(FRAC_PI_2 * t)),
+    }
+  }
+
+  /// Applies the style to the incoming icon at `t` (0 to 1).
+  fn enter(self, icon: Icon, t: f32) -> Icon {
+    let icon = icon.opacity(t);
+    match self {
+      Self::Crossfade => icon,
+      Self::ScaleSwap => icon.scale(t),
+      Self::RotateSwap => icon.rotate(radians(-FRAC_PI_2 * (1.0 - t))),
+    }
+  }
+}
+
+/// Icons shown by an `IconTransition`, kept across renders.
+#[derive(Default)]
+struct TransitionState {
+  current: Option<Icon>,
+  previous: Option<Icon>,
+  /// When the current icon replaced the previous one.
+  changed_at: Option<Instant>,
+  generation: u64,
+}
+
+impl TransitionState {
+  /// Records the icon for this render, starting a transition when its path changed.
+  fn advance(&mut self, icon: &Icon, now: Instant) {
+    match &self.current {
+      Some(current) if current.path == icon.path => {}
+      Some(_) => {
+        self.previous = self.current.replace(icon.clone());
+        self.changed_at = Some(now);
+        self.generation += 1;
+      }
+      None => self.current = Some(icon.clone()),
+    }
+  }
+
+  /// Drops the previous icon once its exit animation has run for `duration`.
+  fn finish(&mut self, now: Instant, duration: Duration) {
+    if self
+      .changed_at
+      .is_some_and(|changed_at| now.duration_since(changed_at) >= duration)
+    {
+      self.previous = None;
+      self.changed_at = None;
+    }
+  }
+}
+
+/// An icon that animates whenever the icon it shows changes.
+///
+/// Render it with the current icon each frame, for example `Play` or `Pause` depending
+/// on playback state. When the icon path differs from the previous render, the old icon
+/// transitions out while the new one transitions in. State is kept per element id, so
+/// every transition on screen needs its own id.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{Icon, IconName, IconTransition, TransitionStyle};
+///
+/// IconTransition::new(
+///     "theme-toggle",
+///     Icon::new(if is_dark { IconName::Sun } else { IconName::Moon }),
+/// )
+/// .style(TransitionStyle::RotateSwap);
+/// ```
+#[derive(IntoElement)]
+pub struct IconTransition {
+  id: ElementId,
+  icon: Icon,
+  style: TransitionStyle,
+  duration: Duration,
+  easing: Rc<dyn Fn(f32) -> f32>,
+}
+
+impl IconTransition {
+  /// Creates a transition showing `icon`.
+  pub fn new(id: impl Into<ElementId>, icon: impl Into<Icon>) -> Self {
+    Self {
+      id: id.into(),
+      icon: icon.into(),
+      style: TransitionStyle::default(),
+      duration: Duration::from_millis(200),
+      easing: Rc::new(ease_in_out),
+    }
+  }
+
+  /// Sets the transition style. Defaults to a crossfade.
+  pub fn style(mut self, style: TransitionStyle) -> Self {
+    self.style = style;
+    self
+  }
+
+  /// Sets the length of the transition. Defaults to 200ms.
+  pub fn duration(mut self, duration: Duration) -> Self {
+    self.duration = duration;
+    self
+  }
+
+  /// Sets the easing function of the transition.
+  pub fn easing(mut self, easing: impl Fn(f32) -> f32 + 'static) -> Self {
+    self.easing = Rc::new(easing);
+    self
+  }
+}
+
+impl RenderOnce for IconTransition {
+  fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+    let state = window.use_keyed_state(self.id.clone(), cx, |_, _| TransitionState::default());
+    let duration = self.duration;
+    let (previous, generation) = state.update(cx, |state, _| {
+      let now = Instant::now();
+      state.advance(&self.icon, now);
+      state.finish(now, duration);
+      (state.previous.clone(), state.generation)
+    });
+
+    let style = self.style;
+    let container = div().id(self.id).relative().flex_none();
+
+    // Nothing to animate until the icon changes, or once the transition has finished.
+    let Some(previous) = previous else {
+      return container.child(self.icon);
+    };
+
+    let exit_easing = self.easing.clone();
+    let enter_easing = self.easing;
+
+    container
+      .child(previous.absolute().top_0().left_0().with_animation(
+        ElementId::NamedInteger("exit".into(), generation),
+        Animation::new(self.duration),
+        move |icon, delta| style.exit(icon, exit_easing(delta)),
+      ))
+      .child(self.icon.with_animation(
+        ElementId::NamedInteger("enter".into(), generation),
+        Animation::new(self.duration),
+        move |icon, delta| style.enter(icon, enter_easing(delta)),
+      ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::IconName;
+
+  #[test]
+  fn test_first_icon_does_not_transition() {
+    let mut state = TransitionState::default();
+    state.advance(&Icon::new(IconName::Play), Instant::now());
+
+    assert!(state.previous.is_none());
+    assert_eq!(state.generation, 0);
+  }
+
+  #[test]
+  fn test_same_icon_does_not_transition() {
+    let mut state = TransitionState::default();
+    state.advance(&Icon::new(IconName::Play), Instant::now());
+    state.advance(&Icon::new(IconName::Play), Instant::now());
+
+    assert!(state.previous.is_none());
+    assert_eq!(state.generation, 0);
+  }
+
+  #[test]
+  fn test_changed_icon_starts_transition() {
+    let mut state = TransitionState::default();
+    state.advance(&Icon::new(IconName::Play), Instant::now());
+    state.advance(&Icon::new(IconName::Pause), Instant::now());
+
+    assert_eq!(
+      state.previous.as_ref().map(|icon| icon.path.as_ref()),
+      Some(IconName::Play.path())
+    );
+    assert_eq!(
+      state.current.as_ref().map(|icon| icon.path.as_ref()),
+      Some(IconName::Pause.path())
+    );
+    assert_eq!(state.generation, 1);
+
+    state.advance(&Icon::new(IconName::Play), Instant::now());
+    assert_eq!(state.generation, 2);
+  }
+
+  #[test]
+  fn test_previous_icon_is_dropped_after_the_transition() {
+    let start = Instant::now();
+    let duration = Duration::from_millis(200);
+    let mut state = TransitionState::default();
+    state.advance(&Icon::new(IconName::Play), start);
+    state.advance(&Icon::new(IconName::Pause), start);
+
+    state.finish(start + Duration::from_millis(100), duration);
+    assert!(state.previous.is_some());
+
+    state.finish(start + duration, duration);
+    assert!(state.previous.is_none());
+    assert_eq!(state.generation, 1);
+  }
+}
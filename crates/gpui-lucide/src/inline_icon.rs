@@ -0,0 +1,357 @@
+//! Icons that sit inside runs of text.
+
+use crate::Icon;
+use crate::icon_fit::aspect_ratio;
+use gpui::{
+  AnyElement, App, AvailableSpace, Bounds, Element, ElementId, GlobalElementId, IntoElement,
+  LayoutId, Pixels, RenderOnce, SharedString, StyleRefinement, Styled, StyledText, TextLayout,
+  Window, div, point, prelude::*,
+};
+use std::ops::Range;
+
+/// Height of capital letters as a fraction of the font size, typical of UI fonts.
+const CAP_HEIGHT: f32 = 0.7;
+
+/// Em space, one font size wide, reserving room for icons in [`InlineText`].
+const ICON_PLACEHOLDER: char = '\u{2003}';
+
+/// An icon sized and placed like a glyph of the surrounding text.
+///
+/// The icon is as tall as the font size, times `scale`, and is centered on the middle
+/// of the capital letters above the baseline of the current `TextStyle`. It occupies
+/// a box as tall as the line, so it lines up with text laid out next to it. Use it
+/// in rows of text elements aligned to the top; for icons inside wrapping text, use
+/// [`InlineText`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{IconName, InlineIcon};
+///
+/// div()
+///     .flex()
+///     .child("Saved")
+///     .child(InlineIcon::new(IconName::Check).scale(0.9));
+/// ```
+#[derive(IntoElement)]
+pub struct InlineIcon {
+  icon: Icon,
+  scale: f32,
+  custom_style: StyleRefinement,
+}
+
+impl InlineIcon {
+  /// Creates an inline icon.
+  pub fn new(icon: impl Into<Icon>) -> Self {
+    Self {
+      icon: icon.into(),
+      scale: 1.0,
+      custom_style: StyleRefinement::default(),
+    }
+  }
+
+  /// Sets the icon height relative to the font size. Defaults to 1.
+  pub fn scale(mut self, scale: f32) -> Self {
+    self.scale = scale;
+    self
+  }
+}
+
+impl Styled for InlineIcon {
+  fn style(&mut self) -> &mut StyleRefinement {
+    &mut self.custom_style
+  }
+}
+
+impl RenderOnce for InlineIcon {
+  fn render(self, window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    let metrics = LineMetrics::current(window);
+    let icon_size = metrics.font_size * self.scale;
+    let top = optical_top(metrics.baseline, metrics.font_size, icon_size);
+
+    let mut base = div();
+    *base.style() = self.custom_style;
+
+    base
+      .flex()
+      .flex_col()
+      .flex_none()
+      .h(metrics.line_height)
+      .child(self.icon.h(icon_size).mt(top))
+  }
+}
+
+/// Font size, line height and baseline of the current `TextStyle`.
+struct LineMetrics {
+  font_size: Pixels,
+  line_height: Pixels,
+  /// Offset of the baseline from the top of the line box.
+  baseline: Pixels,
+}
+
+impl LineMetrics {
+  fn current(window: &Window) -> Self {
+    let text_style = window.text_style();
+    let rem_size = window.rem_size();
+    let font_size = text_style.font_size.to_pixels(rem_size);
+    let line_height = text_style.line_height_in_pixels(rem_size);
+    let text_system = window.text_system();
+    let font_id = text_system.resolve_font(&text_style.font());
+    let baseline = text_system.baseline_offset(font_id, font_size, line_height);
+    Self {
+      font_size,
+      line_height,
+      baseline,
+    }
+  }
+}
+
+/// Returns the offset from the top of the line box that centers an icon of
+/// `icon_size` on the capital letters sitting on `baseline`.
+fn optical_top(baseline: Pixels, font_size: Pixels, icon_size: Pixels) -> Pixels {
+  let center = baseline - font_size * (CAP_HEIGHT / 2.);
+  center - icon_size / 2.
+}
+
+/// A segment of [`InlineText`].
+enum Segment {
+  Text(SharedString),
+  Icon(Icon, f32),
+}
+
+/// Text with icons embedded in it, wrapping like a paragraph.
+///
+/// The text is laid out as one gpui [`StyledText`], so it wraps, shapes and inherits
+/// color, size and font like any other text. Each icon reserves a run of em spaces in
+/// that text, one per font size of icon width, and is drawn over it once the text is
+/// laid out, on the text baseline like an [`InlineIcon`]. Icons wrap with the text
+/// and follow its color.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{IconName, InlineText};
+///
+/// InlineText::new()
+///     .text("Press ")
+///     .icon(IconName::CornerDownLeft)
+///     .text(" to continue");
+/// ```
+#[derive(IntoElement, Default)]
+pub struct InlineText {
+  segments: Vec<Segment>,
+  custom_style: StyleRefinement,
+}
+
+impl InlineText {
+  /// Creates empty inline text.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Appends text.
+  pub fn text(mut self, text: impl Into<SharedString>) -> Self {
+    self.segments.push(Segment::Text(text.into()));
+    self
+  }
+
+  /// Appends an icon as tall as the font size.
+  pub fn icon(self, icon: impl Into<Icon>) -> Self {
+    self.icon_scaled(icon, 1.0)
+  }
+
+  /// Appends an icon with a height relative to the font size.
+  pub fn icon_scaled(mut self, icon: impl Into<Icon>, scale: f32) -> Self {
+    self.segments.push(Segment::Icon(icon.into(), scale));
+    self
+  }
+}
+
+impl Styled for InlineText {
+  fn style(&mut self) -> &mut StyleRefinement {
+    &mut self.custom_style
+  }
+}
+
+impl RenderOnce for InlineText {
+  fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    let (text, icons) = inline_runs(self.segments);
+    let text = StyledText::new(text);
+    let layout = text.layout().clone();
+
+    let mut base = div();
+    *base.style() = self.custom_style;
+
+    base.child(InlineTextElement {
+      text: text.into_any_element(),
+      layout,
+      icons,
+    })
+  }
+}
+
+/// An icon of [`InlineText`] and the placeholder it is drawn over.
+struct InlineRun {
+  /// Byte range of the placeholder in the text.
+  range: Range<usize>,
+  icon: Icon,
+  scale: f32,
+}
+
+/// Joins the segments into one string, with a placeholder in place of every icon.
+fn inline_runs(segments: Vec<Segment>) -> (SharedString, Vec<InlineRun>) {
+  let mut text = String::new();
+  let mut runs = Vec::new();
+  for segment in segments {
+    match segment {
+      Segment::Text(segment) => text.push_str(&segment),
+      Segment::Icon(icon, scale) => {
+        let aspect = icon.view_box.and_then(aspect_ratio).unwrap_or(1.0);
+        let width = (scale * aspect).ceil().max(1.0) as usize;
+        let start = text.len();
+        text.extend(std::iter::repeat_n(ICON_PLACEHOLDER, width));
+        runs.push(InlineRun {
+          range: start..text.len(),
+          icon,
+          scale,
+        });
+      }
+    }
+  }
+  (text.into(), runs)
+}
+
+/// Lays out the text of [`InlineText`], then places each icon over its placeholder.
+struct InlineTextElement {
+  text: AnyElement,
+  layout: TextLayout,
+  icons: Vec<InlineRun>,
+}
+
+impl IntoElement for InlineTextElement {
+  type Element = Self;
+
+  fn into_element(self) -> Self::Element {
+    self
+  }
+}
+
+impl Element for InlineTextElement {
+  type RequestLayoutState = ();
+  type PrepaintState = Vec<AnyElement>;
+
+  fn id(&self) -> Option<ElementId> {
+    None
+  }
+
+  fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+    None
+  }
+
+  fn request_layout(
+    &mut self,
+    _id: Option<&GlobalElementId>,
+    _inspector_id: Option<&gpui::InspectorElementId>,
+    window: &mut Window,
+    cx: &mut App,
+  ) -> (LayoutId, Self::RequestLayoutState) {
+    (self.text.request_layout(window, cx), ())
+  }
+
+  fn prepaint(
+    &mut self,
+    _id: Option<&GlobalElementId>,
+    _inspector_id: Option<&gpui::InspectorElementId>,
+    _bounds: Bounds<Pixels>,
+    _request_layout: &mut Self::RequestLayoutState,
+    window: &mut Window,
+    cx: &mut App,
+  ) -> Self::PrepaintState {
+    self.text.prepaint(window, cx);
+
+    let metrics = LineMetrics::current(window);
+    let layout = &self.layout;
+    self
+      .icons
+      .drain(..)
+      .filter_map(|run| {
+        let start = layout.position_for_index(run.range.start)?;
+        // The placeholder's advance, unless it wrapped onto the next line.
+        let end = layout
+          .position_for_index(run.range.end)
+          .filter(|end| end.y == start.y);
+
+        let icon_size = metrics.font_size * run.scale;
+        let mut icon = run.icon.h(icon_size).into_any_element();
+        let size = icon.layout_as_root(AvailableSpace::min_size(), window, cx);
+        let advance = end.map_or(size.width, |end| end.x - start.x);
+        let origin = point(
+          start.x + (advance - size.width) / 2.,
+          start.y + optical_top(metrics.baseline, metrics.font_size, icon_size),
+        );
+        icon.prepaint_at(origin, window, cx);
+        Some(icon)
+      })
+      .collect()
+  }
+
+  fn paint(
+    &mut self,
+    _id: Option<&GlobalElementId>,
+    _inspector_id: Option<&gpui::InspectorElementId>,
+    _bounds: Bounds<Pixels>,
+    _request_layout: &mut Self::RequestLayoutState,
+    icons: &mut Self::PrepaintState,
+    window: &mut Window,
+    cx: &mut App,
+  ) {
+    self.text.paint(window, cx);
+    for icon in icons {
+      icon.paint(window, cx);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::IconName;
+  use gpui::px;
+
+  #[test]
+  fn test_optical_top_centers_on_capitals() {
+    // A 20px line with its baseline 15px down: capitals span 15 - 11.2 = 3.8 to 15,
+    // so a 16px icon centered on them starts at 9.4 - 8.
+    let top = optical_top(px(15.), px(16.), px(16.));
+    assert!((f32::from(top) - 1.4).abs() < 1e-4);
+  }
+
+  #[test]
+  fn test_inline_runs_reserve_placeholders() {
+    let text = InlineText::new()
+      .text("Press ")
+      .icon(IconName::CornerDownLeft)
+      .icon_scaled(IconName::Check, 1.5)
+      .text(" to continue");
+    let (text, runs) = inline_runs(text.segments);
+
+    assert_eq!(text.as_ref(), "Press \u{2003}\u{2003}\u{2003} to continue");
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].range, 6..9);
+    assert_eq!(runs[1].range, 9..15);
+    assert_eq!(runs[1].scale, 1.5);
+  }
+
+  #[test]
+  fn test_builder_keeps_segment_order() {
+    let text = InlineText::new()
+      .text("Press ")
+      .icon(IconName::CornerDownLeft)
+      .icon_scaled(IconName::Check, 0.8)
+      .text(" to continue");
+
+    assert_eq!(text.segments.len(), 4);
+    assert!(matches!(&text.segments[0], Segment::Text(text) if text.as_ref() == "Press "));
+    assert!(matches!(&text.segments[2], Segment::Icon(_, scale) if *scale == 0.8));
+  }
+}
@@ -0,0 +1,172 @@
+//! Icon with an id, accepting mouse handlers and tooltips.
+
+use crate::Icon;
+use gpui::{
+  App, Context, Div, ElementId, Hsla, InteractiveElement, Interactivity, IntoElement, Length,
+  ParentElement, Render, RenderOnce, SharedString, Stateful, StatefulInteractiveElement,
+  StyleRefinement, Styled, Window, div, prelude::*, px,
+};
+
+/// Group name linking the hit area to the icon's hover and active colors.
+const GROUP: &str = "lucide-interactive-icon";
+
+/// An icon that handles clicks, hover and tooltips.
+///
+/// Created with [`Icon::id`]. The icon is drawn inside a hit area that receives the
+/// mouse events. The hit area matches the icon by default; `hit_area` enlarges it for
+/// small icons without changing how the icon looks. Styles set through `Styled` apply
+/// to the hit area.
+///
+/// Hover and active colors tint the icon, so they have no effect on icons painted
+/// with [`Icon::gradient`], which are drawn as images.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{Icon, IconName};
+///
+/// Icon::new(IconName::X)
+///     .color(gpui::rgb(0x888888))
+///     .id("close")
+///     .hover_color(gpui::rgb(0xffffff))
+///     .hit_area(gpui::px(24.))
+///     .cursor_pointer()
+///     .tooltip_text("Close")
+///     .on_click(|_, window, _| window.remove_window());
+/// ```
+#[derive(IntoElement)]
+pub struct InteractiveIcon {
+  base: Stateful<Div>,
+  icon: Icon,
+  hover_color: Option<Hsla>,
+  active_color: Option<Hsla>,
+}
+
+impl InteractiveIcon {
+  pub(crate) fn new(id: impl Into<ElementId>, icon: Icon) -> Self {
+    Self {
+      base: div().id(id),
+      icon,
+      hover_color: None,
+      active_color: None,
+    }
+  }
+
+  /// Sets the icon color while the hit area is hovered. Ignored for gradient icons.
+  pub fn hover_color(mut self, color: impl Into<Hsla>) -> Self {
+    self.hover_color = Some(color.into());
+    self
+  }
+
+  /// Sets the icon color while the hit area is pressed. Ignored for gradient icons.
+  pub fn active_color(mut self, color: impl Into<Hsla>) -> Self {
+    self.active_color = Some(color.into());
+    self
+  }
+
+  /// Sets the size of the square area that receives mouse events, keeping the icon
+  /// centered inside it.
+  pub fn hit_area(mut self, size: impl Into<Length>) -> Self {
+    self.base = self.base.size(size);
+    self
+  }
+
+  /// Shows a text tooltip when the icon is hovered.
+  pub fn tooltip_text(mut self, text: impl Into<SharedString>) -> Self {
+    let text = text.into();
+    self.base = self.base.tooltip(move |_, cx| {
+      let text = text.clone();
+      cx.new(|_| IconTooltip { text }).into()
+    });
+    self
+  }
+}
+
+impl Styled for InteractiveIcon {
+  fn style(&mut self) -> &mut StyleRefinement {
+    self.base.style()
+  }
+}
+
+impl InteractiveElement for InteractiveIcon {
+  fn interactivity(&mut self) -> &mut Interactivity {
+    self.base.interactivity()
+  }
+}
+
+impl StatefulInteractiveElement for InteractiveIcon {}
+
+impl RenderOnce for InteractiveIcon {
+  fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+    let hover_color = self.hover_color;
+    let active_color = self.active_color;
+    let icon = self.icon.render_debuggable(cx, |mut icon, cx| {
+      // Gradient icons are images, which hover and active colors cannot tint.
+      if let Some(gradient) = icon.gradient.take() {
+        return icon.into_gradient_img(gradient, window, cx);
+      }
+      icon
+        .into_svg(window, cx)
+        .when_some(hover_color, |this, color| {
+          this.group_hover(GROUP, move |style| style.text_color(color))
+        })
+        .when_some(active_color, |this, color| {
+          this.group_active(GROUP, move |style| style.text_color(color))
+        })
+        .into_any_element()
+    });
+
+    self
+      .base
+      .group(GROUP)
+      .flex()
+      .flex_none()
+      .items_center()
+      .justify_center()
+      .child(icon)
+  }
+}
+
+/// Tooltip view showing a single line of text.
+struct IconTooltip {
+  text: SharedString,
+}
+
+impl Render for IconTooltip {
+  fn render(&mut self, window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+    let text_style = window.text_style();
+    div()
+      .px_2()
+      .py_1()
+      .rounded(px(4.))
+      .bg(gpui::black().opacity(0.85))
+      .text_color(gpui::white())
+      .text_size(text_style.font_size)
+      .child(self.text.clone())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::IconName;
+  use gpui::rgb;
+
+  #[test]
+  fn test_id_keeps_icon_configuration() {
+    let icon = Icon::new(IconName::X).color(rgb(0x888888)).id("close");
+    assert_eq!(icon.icon.path.as_ref(), "icons/x.svg");
+    assert!(icon.icon.color.is_some());
+  }
+
+  #[test]
+  fn test_hover_and_active_colors() {
+    let icon = Icon::new(IconName::X)
+      .id("close")
+      .hover_color(rgb(0xffffff))
+      .active_color(rgb(0xff0000));
+
+    assert!(icon.hover_color.is_some());
+    assert!(icon.active_color.is_some());
+  }
+}
@@ -0,0 +1,304 @@
+//! Keystrokes drawn as rows of icons, for keybinding hints.
+
+use crate::{Icon, IconName, IconSize};
+use gpui::{
+  App, Hsla, IntoElement, InvalidKeystrokeError, Keystroke, RenderOnce, SharedString,
+  StyleRefinement, Styled, Window, div, prelude::*, rems,
+};
+
+/// Platform whose conventions decide how modifiers are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyPlatform {
+  /// Modifier symbols, such as `⌘` and `⌥`, in the order `⌃⌥⇧⌘`.
+  Mac,
+  /// Modifier names in the order `Ctrl Alt Shift Super`.
+  Linux,
+  /// Modifier names in the order `Ctrl Alt Shift Win`.
+  Windows,
+}
+
+impl KeyPlatform {
+  /// Returns the platform the app was built for.
+  pub fn current() -> Self {
+    if cfg!(target_os = "macos") {
+      Self::Mac
+    } else if cfg!(target_os = "windows") {
+      Self::Windows
+    } else {
+      Self::Linux
+    }
+  }
+}
+
+/// One part of a rendered keystroke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyGlyph {
+  /// A key or modifier with a Lucide glyph.
+  Icon(IconName),
+  /// A key or modifier without a glyph, shown as text.
+  Text(SharedString),
+}
+
+/// Returns the glyphs for `keystroke`, modifiers first.
+pub fn keystroke_glyphs(keystroke: &Keystroke, platform: KeyPlatform) -> Vec<KeyGlyph> {
+  let modifiers = &keystroke.modifiers;
+  let mut glyphs = Vec::new();
+  let mut push = |enabled: bool, glyph: KeyGlyph| {
+    if enabled {
+      glyphs.push(glyph);
+    }
+  };
+
+  match platform {
+    KeyPlatform::Mac => {
+      push(modifiers.function, KeyGlyph::Icon(IconName::Globe));
+      push(modifiers.control, KeyGlyph::Icon(IconName::ChevronUp));
+      push(modifiers.alt, KeyGlyph::Icon(IconName::Option));
+      push(modifiers.shift, KeyGlyph::Icon(IconName::ArrowBigUp));
+      push(modifiers.platform, KeyGlyph::Icon(IconName::Command));
+    }
+    KeyPlatform::Linux | KeyPlatform::Windows => {
+      let platform_name = if platform == KeyPlatform::Windows {
+        "Win"
+      } else {
+        "Super"
+      };
+      push(modifiers.function, KeyGlyph::Text("Fn".into()));
+      push(modifiers.control, KeyGlyph::Text("Ctrl".into()));
+      push(modifiers.alt, KeyGlyph::Text("Alt".into()));
+      push(modifiers.shift, KeyGlyph::Text("Shift".into()));
+      push(modifiers.platform, KeyGlyph::Text(platform_name.into()));
+    }
+  }
+
+  glyphs.push(key_glyph(&keystroke.key));
+  glyphs
+}
+
+fn key_glyph(key: &str) -> KeyGlyph {
+  let icon = match key {
+    "enter" => Some(IconName::CornerDownLeft),
+    "backspace" => Some(IconName::Delete),
+    "tab" => Some(IconName::ArrowRightToLine),
+    "space" => Some(IconName::Space),
+    "up" => Some(IconName::ArrowUp),
+    "down" => Some(IconName::ArrowDown),
+    "left" => Some(IconName::ArrowLeft),
+    "right" => Some(IconName::ArrowRight),
+    _ => None,
+  };
+  if let Some(icon) = icon {
+    return KeyGlyph::Icon(icon);
+  }
+
+  let text = match key {
+    "escape" => "Esc".to_string(),
+    "delete" => "Del".to_string(),
+    "insert" => "Ins".to_string(),
+    "pageup" => "PgUp".to_string(),
+    "pagedown" => "PgDn".to_string(),
+    _ => {
+      let mut chars = key.chars();
+      match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+      }
+    }
+  };
+  KeyGlyph::Text(text.into())
+}
+
+/// A keystroke, or a sequence of them, drawn as a row of modifier and key glyphs.
+///
+/// Keys without a Lucide glyph, such as letters or `escape`, are shown as text. On
+/// macOS modifiers use their usual symbols; elsewhere they are spelled out.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{IconSize, KeystrokeIcons};
+///
+/// let hint = KeystrokeIcons::from_binding("cmd-shift-p")?
+///     .with_size(IconSize::Small)
+///     .color(gpui::rgb(0x8b8b8b));
+/// ```
+#[derive(IntoElement)]
+pub struct KeystrokeIcons {
+  keystrokes: Vec<Keystroke>,
+  platform: KeyPlatform,
+  color: Option<Hsla>,
+  size: Option<IconSize>,
+  custom_style: StyleRefinement,
+}
+
+impl KeystrokeIcons {
+  /// Creates the glyphs for a single keystroke.
+  pub fn new(keystroke: Keystroke) -> Self {
+    Self::sequence(vec![keystroke])
+  }
+
+  /// Creates the glyphs for keystrokes pressed one after another.
+  pub fn sequence(keystrokes: Vec<Keystroke>) -> Self {
+    Self {
+      keystrokes,
+      platform: KeyPlatform::current(),
+      color: None,
+      size: None,
+      custom_style: StyleRefinement::default(),
+    }
+  }
+
+  /// Parses a binding such as `cmd-shift-p` or the sequence `cmd-k cmd-s`.
+  pub fn from_binding(binding: &str) -> Result<Self, InvalidKeystrokeError> {
+    let keystrokes = binding
+      .split_whitespace()
+      .map(Keystroke::parse)
+      .collect::<Result<_, _>>()?;
+    Ok(Self::sequence(keystrokes))
+  }
+
+  /// Shows modifiers the way `platform` does. Defaults to [`KeyPlatform::current`].
+  pub fn platform(mut self, platform: KeyPlatform) -> Self {
+    self.platform = platform;
+    self
+  }
+
+  /// Sets the color of glyphs and text.
+  pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+    self.color = Some(color.into());
+    self
+  }
+
+  /// Sets the size of glyphs and text.
+  pub fn with_size(mut self, size: IconSize) -> Self {
+    self.size = Some(size);
+    self
+  }
+
+  /// Returns the glyphs of each keystroke.
+  pub fn glyphs(&self) -> Vec<Vec<KeyGlyph>> {
+    self
+      .keystrokes
+      .iter()
+      .map(|keystroke| keystroke_glyphs(keystroke, self.platform))
+      .collect()
+  }
+}
+
+impl From<Keystroke> for KeystrokeIcons {
+  fn from(keystroke: Keystroke) -> Self {
+    Self::new(keystroke)
+  }
+}
+
+impl Styled for KeystrokeIcons {
+  fn style(&mut self) -> &mut StyleRefinement {
+    &mut self.custom_style
+  }
+}
+
+impl RenderOnce for KeystrokeIcons {
+  fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    let color = self.color;
+    let size = self.size;
+    let glyphs = self.glyphs();
+
+    let mut base = div();
+    *base.style() = self.custom_style;
+
+    base
+      .flex()
+      .flex_row()
+      .items_center()
+      .gap_1p5()
+      .children(glyphs.into_iter().map(|keystroke| {
+        div()
+          .flex()
+          .flex_row()
+          .items_center()
+          .gap_0p5()
+          .children(keystroke.into_iter().map(|glyph| {
+            match glyph {
+              KeyGlyph::Icon(icon) => Icon::new(icon)
+                .when_some(color, |icon, color| icon.color(color))
+                .when_some(size, |icon, size| icon.with_size(size))
+                .into_any_element(),
+              KeyGlyph::Text(text) => div()
+                .when_some(color, |text, color| text.text_color(color))
+                .when_some(size, |text, size| text.text_size(rems(size.to_rems())))
+                .child(text)
+                .into_any_element(),
+            }
+          }))
+      }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn glyphs(binding: &str, platform: KeyPlatform) -> Vec<KeyGlyph> {
+    keystroke_glyphs(&Keystroke::parse(binding).unwrap(), platform)
+  }
+
+  fn text(text: &str) -> KeyGlyph {
+    KeyGlyph::Text(text.into())
+  }
+
+  #[test]
+  fn test_mac_modifiers_use_symbols_in_order() {
+    assert_eq!(
+      glyphs("cmd-shift-alt-ctrl-p", KeyPlatform::Mac),
+      [
+        KeyGlyph::Icon(IconName::ChevronUp),
+        KeyGlyph::Icon(IconName::Option),
+        KeyGlyph::Icon(IconName::ArrowBigUp),
+        KeyGlyph::Icon(IconName::Command),
+        text("P"),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_other_platforms_spell_out_modifiers() {
+    assert_eq!(
+      glyphs("shift-ctrl-enter", KeyPlatform::Linux),
+      [
+        text("Ctrl"),
+        text("Shift"),
+        KeyGlyph::Icon(IconName::CornerDownLeft),
+      ]
+    );
+    assert_eq!(
+      glyphs("super-e", KeyPlatform::Windows),
+      [text("Win"), text("E")]
+    );
+  }
+
+  #[test]
+  fn test_keys_fall_back_to_text() {
+    assert_eq!(
+      glyphs("backspace", KeyPlatform::Mac),
+      [KeyGlyph::Icon(IconName::Delete)]
+    );
+    assert_eq!(glyphs("escape", KeyPlatform::Mac), [text("Esc")]);
+    assert_eq!(glyphs("f12", KeyPlatform::Mac), [text("F12")]);
+    assert_eq!(glyphs("home", KeyPlatform::Mac), [text("Home")]);
+    assert_eq!(glyphs("/", KeyPlatform::Mac), [text("/")]);
+  }
+
+  #[test]
+  fn test_from_binding_parses_sequences() {
+    let icons = KeystrokeIcons::from_binding("cmd-k  cmd-s")
+      .unwrap()
+      .platform(KeyPlatform::Mac);
+    assert_eq!(
+      icons.glyphs(),
+      [
+        vec![KeyGlyph::Icon(IconName::Command), text("K")],
+        vec![KeyGlyph::Icon(IconName::Command), text("S")],
+      ]
+    );
+  }
+}
@@ -19,6 +19,28 @@
 //! }
 //! ```
 //!
+//! ## Overview
+//!
+//! - [`Icon`] draws an icon with a color, a semantic [`IconColor`] from the
+//!   [`IconTheme`], automatic contrast or an [`IconGradient`]. Its rotation, scaling,
+//!   flips and translation compose into an [`IconTransform`].
+//! - [`Icon::id`] makes an icon an [`InteractiveIcon`] with click, hover and tooltip
+//!   handlers.
+//! - [`IconLabel`], [`InlineIcon`], [`InlineText`] and [`KeystrokeIcons`] lay icons out
+//!   with text.
+//! - [`VectorIcon`] paints icons with gpui paths, [`AnimatedIcon`] draws their strokes
+//!   on and [`IconTransition`] animates between icons.
+//! - [`IconPicker`] and [`TextInput`] make a searchable icon grid, ranked by the
+//!   [`search`] module.
+//! - [`file_icons`] picks icons for paths, directories, MIME types and languages.
+//! - [`set_icon_fallback`] and [`check_icon_assets`] handle missing assets, and
+//!   [`set_icon_debug`] outlines icon layout.
+//! - [`SvgExt`] returns the raw markup and, with the `rasterize` feature,
+//!   `RasterizeExt` renders bitmaps.
+//!
+//! `IconName` and the icon metadata come from the `lucide-core` crate, which does not
+//! depend on gpui.
+//!
 //! ## Custom Icons
 //!
 //! You can also define your own icons by implementing the `IconNamed` trait:
@@ -43,82 +65,52 @@
 //! // Use it the same way
 //! let icon = Icon::new(MyCustomIcon::Logo);
 //! ```
+//!
+//! Icons with a non-square viewBox, such as wide logos, keep their aspect ratio; see
+//! [`IconFit`].
 
+pub mod file_icons;
+pub mod search;
+
+mod animated_icon;
+mod contrast;
 mod icon;
+mod icon_assets;
+mod icon_debug;
+mod icon_fit;
+mod icon_gradient;
+mod icon_label;
+mod icon_picker;
+mod icon_theme;
+mod icon_transition;
+mod inline_icon;
+mod interactive_icon;
+mod keystroke_icons;
+#[cfg(feature = "rasterize")]
+mod raster;
+mod svg;
+mod text_input;
+mod transform;
+mod vector_icon;
 
+pub use animated_icon::*;
+pub use contrast::*;
 pub use icon::*;
-
-// Include the generated icon names
-include!(concat!(env!("OUT_DIR"), "/icons_generated.rs"));
-
-#[cfg(test)]
-mod tests {
-  use super::*;
-  use std::collections::HashSet;
-  use std::ffi::OsStr;
-  use std::fs;
-  use std::path::Path;
-
-  #[test]
-  fn test_icon_name_path() {
-    let path = IconName::Heart.path();
-    assert!(path.starts_with("icons/"));
-    assert!(path.ends_with(".svg"));
-  }
-
-  #[test]
-  fn test_icon_name_display() {
-    let name = IconName::Heart;
-    assert_eq!(name.to_string(), "heart");
-  }
-
-  #[test]
-  fn test_icon_count() {
-    assert!(IconName::count() > 1000);
-  }
-
-  #[test]
-  fn test_all_icons_iterator() {
-    let count = IconName::all().count();
-    assert_eq!(count, IconName::count());
-  }
-
-  #[test]
-  fn test_icon_count_matches_icons_directory() {
-    let icons_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../icons");
-    let svg_count = fs::read_dir(&icons_dir)
-      .expect("icons directory should be readable")
-      .filter_map(Result::ok)
-      .filter(|entry| entry.path().extension() == Some(OsStr::new("svg")))
-      .count();
-
-    assert_eq!(IconName::count(), svg_count);
-  }
-
-  #[test]
-  fn test_all_icon_name_path_mappings() {
-    for icon in IconName::all() {
-      let expected = format!("icons/{}.svg", icon.name());
-      assert_eq!(icon.path(), expected.as_str());
-    }
-  }
-
-  #[test]
-  fn test_names_and_paths_are_unique() {
-    let mut names = HashSet::new();
-    let mut paths = HashSet::new();
-
-    for icon in IconName::all() {
-      assert!(
-        names.insert(icon.name()),
-        "duplicate icon name: {}",
-        icon.name()
-      );
-      assert!(
-        paths.insert(icon.path()),
-        "duplicate icon path: {}",
-        icon.path()
-      );
-    }
-  }
-}
+pub use icon_assets::*;
+pub use icon_debug::*;
+pub use icon_fit::*;
+pub use icon_gradient::*;
+pub use icon_label::*;
+pub use icon_picker::*;
+pub use icon_theme::*;
+pub use icon_transition::*;
+pub use inline_icon::*;
+pub use interactive_icon::*;
+pub use keystroke_icons::*;
+pub use lucide_core::*;
+#[cfg(feature = "rasterize")]
+pub use raster::*;
+pub use svg::*;
+pub use text_input::*;
+pub use transform::*;
+pub use vector_icon::*;
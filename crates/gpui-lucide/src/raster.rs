@@ -0,0 +1,236 @@
+//! CPU rasterization of icons into bitmaps.
+//!
+//! Enabled with the `rasterize` feature. Rendering runs on the CPU through `resvg`, so it
+//! works headless and can produce window icons, notification images and drag previews
+//! where an `Icon` element cannot be used.
+
+use crate::{IconName, SvgExt, SvgOptions};
+use gpui::{Hsla, Image, ImageFormat, RenderImage};
+use image::{Frame, ImageEncoder, RgbaImage, codecs::png::PngEncoder};
+use resvg::{tiny_skia, usvg};
+use std::fmt;
+use std::sync::Arc;
+
+/// Error returned when SVG markup cannot be rasterized or encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RasterizeError {
+  /// The markup is not valid SVG.
+  Parse(String),
+  /// The requested size is zero.
+  EmptySize,
+  /// PNG encoding failed.
+  Encode(String),
+}
+
+impl fmt::Display for RasterizeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Parse(message) => write!(f, "invalid svg: {}", message),
+      Self::EmptySize => f.write_str("cannot rasterize to an empty image"),
+      Self::Encode(message) => write!(f, "png encoding failed: {}", message),
+    }
+  }
+}
+
+impl std::error::Error for RasterizeError {}
+
+/// Rasterizes SVG markup into a square RGBA image of `size_px` pixels.
+///
+/// The drawing is scaled to fit and centered, keeping its aspect ratio.
+pub fn rasterize_svg(markup: &str, size_px: u32) -> Result<RgbaImage, RasterizeError> {
+  if size_px == 0 {
+    return Err(RasterizeError::EmptySize);
+  }
+
+  let tree = usvg::Tree::from_str(markup, &usvg::Options::default())
+    .map_err(|e| RasterizeError::Parse(e.to_string()))?;
+  let mut pixmap = tiny_skia::Pixmap::new(size_px, size_px).ok_or(RasterizeError::EmptySize)?;
+
+  let tree_size = tree.size();
+  let scale = size_px as f32 / tree_size.width().max(tree_size.height());
+  let offset_x = (size_px as f32 - tree_size.width() * scale) / 2.0;
+  let offset_y = (size_px as f32 - tree_size.height() * scale) / 2.0;
+  let transform = tiny_skia::Transform::from_scale(scale, scale).post_translate(offset_x, offset_y);
+  resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+  // tiny-skia stores premultiplied alpha, image buffers expect straight alpha.
+  let data = pixmap
+    .pixels()
+    .iter()
+    .flat_map(|pixel| {
+      let color = pixel.demultiply();
+      [color.red(), color.green(), color.blue(), color.alpha()]
+    })
+    .collect();
+
+  Ok(RgbaImage::from_raw(size_px, size_px, data).expect("pixmap size matches image size"))
+}
+
+/// Encodes an RGBA image as PNG.
+pub fn encode_png(image: &RgbaImage) -> Result<Vec<u8>, RasterizeError> {
+  let mut bytes = Vec::new();
+  PngEncoder::new(&mut bytes)
+    .write_image(
+      image.as_raw(),
+      image.width(),
+      image.height(),
+      image::ExtendedColorType::Rgba8,
+    )
+    .map_err(|e| RasterizeError::Encode(e.to_string()))?;
+  Ok(bytes)
+}
+
+/// Wraps an RGBA image into a `RenderImage` that can be painted directly.
+pub fn render_image(image: RgbaImage) -> Arc<RenderImage> {
+  let mut image = image;
+  for pixel in image.pixels_mut() {
+    straight_rgba_to_premultiplied_bgra(&mut pixel.0);
+  }
+  Arc::new(RenderImage::new(vec![Frame::new(image)]))
+}
+
+/// Converts a straight-alpha RGBA pixel into the premultiplied BGRA that gpui paints.
+fn straight_rgba_to_premultiplied_bgra(pixel: &mut [u8; 4]) {
+  pixel.swap(0, 2);
+  let alpha = pixel[3] as u16;
+  for channel in &mut pixel[..3] {
+    *channel = ((*channel as u16 * alpha + 127) / 255) as u8;
+  }
+}
+
+/// Extends `IconName` with CPU rendering into bitmaps.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{IconName, RasterizeExt};
+///
+/// let image = IconName::Bell.rasterize(64, gpui::white(), 2.0);
+/// assert_eq!(image.dimensions(), (64, 64));
+/// ```
+pub trait RasterizeExt {
+  /// Rasterizes the icon into a square RGBA image.
+  fn rasterize(&self, size_px: u32, color: impl Into<Hsla>, stroke_width: f32) -> RgbaImage;
+
+  /// Rasterizes the icon and encodes it as PNG.
+  fn to_png(
+    &self,
+    size_px: u32,
+    color: impl Into<Hsla>,
+    stroke_width: f32,
+  ) -> Result<Vec<u8>, RasterizeError> {
+    encode_png(&self.rasterize(size_px, color, stroke_width))
+  }
+
+  /// Rasterizes the icon into a PNG-backed `gpui::Image`, for use with `img()`.
+  fn to_image(
+    &self,
+    size_px: u32,
+    color: impl Into<Hsla>,
+    stroke_width: f32,
+  ) -> Result<Image, RasterizeError> {
+    let png = self.to_png(size_px, color, stroke_width)?;
+    Ok(Image::from_bytes(ImageFormat::Png, png))
+  }
+
+  /// Rasterizes the icon into a `RenderImage`, skipping PNG encoding.
+  fn to_render_image(
+    &self,
+    size_px: u32,
+    color: impl Into<Hsla>,
+    stroke_width: f32,
+  ) -> Arc<RenderImage> {
+    render_image(self.rasterize(size_px, color, stroke_width))
+  }
+}
+
+impl RasterizeExt for IconName {
+  fn rasterize(&self, size_px: u32, color: impl Into<Hsla>, stroke_width: f32) -> RgbaImage {
+    let markup = self.svg_with(SvgOptions::new().color(color).stroke_width(stroke_width));
+    rasterize_svg(&markup, size_px.max(1)).expect("bundled icons are valid svg")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use gpui::rgb;
+
+  fn opaque_pixels(image: &RgbaImage) -> Vec<[u8; 4]> {
+    image
+      .pixels()
+      .filter(|pixel| pixel.0[3] == 255)
+      .map(|pixel| pixel.0)
+      .collect()
+  }
+
+  #[test]
+  fn test_rasterize_uses_requested_size() {
+    let image = IconName::Heart.rasterize(48, rgb(0xffffff), 2.0);
+    assert_eq!(image.dimensions(), (48, 48));
+  }
+
+  #[test]
+  fn test_rasterize_uses_requested_color() {
+    let image = IconName::Square.rasterize(64, rgb(0xe94560), 2.0);
+    let pixels = opaque_pixels(&image);
+
+    assert!(!pixels.is_empty());
+    assert!(
+      pixels
+        .iter()
+        .all(|pixel| *pixel == [0xe9, 0x45, 0x60, 0xff])
+    );
+  }
+
+  #[test]
+  fn test_rasterize_stroke_width_changes_coverage() {
+    let thin = IconName::Square.rasterize(64, rgb(0x000000), 1.0);
+    let thick = IconName::Square.rasterize(64, rgb(0x000000), 3.0);
+    assert!(opaque_pixels(&thick).len() > opaque_pixels(&thin).len());
+  }
+
+  #[test]
+  fn test_rasterize_leaves_background_transparent() {
+    let image = IconName::Circle.rasterize(32, rgb(0xffffff), 2.0);
+    assert_eq!(image.get_pixel(0, 0).0[3], 0);
+    assert_eq!(image.get_pixel(16, 16).0[3], 0);
+  }
+
+  #[test]
+  fn test_rasterize_svg_rejects_invalid_markup() {
+    assert!(matches!(
+      rasterize_svg("not svg", 16),
+      Err(RasterizeError::Parse(_))
+    ));
+  }
+
+  #[test]
+  fn test_rasterize_svg_rejects_empty_size() {
+    assert_eq!(
+      rasterize_svg(IconName::Heart.svg(), 0),
+      Err(RasterizeError::EmptySize)
+    );
+  }
+
+  #[test]
+  fn test_render_image_pixels_are_premultiplied_bgra() {
+    let mut opaque = [0xe9, 0x45, 0x60, 0xff];
+    straight_rgba_to_premultiplied_bgra(&mut opaque);
+    assert_eq!(opaque, [0x60, 0x45, 0xe9, 0xff]);
+
+    let mut translucent = [0xff, 0x80, 0x00, 0x80];
+    straight_rgba_to_premultiplied_bgra(&mut translucent);
+    assert_eq!(translucent, [0x00, 0x40, 0x80, 0x80]);
+
+    let mut transparent = [0xff, 0xff, 0xff, 0x00];
+    straight_rgba_to_premultiplied_bgra(&mut transparent);
+    assert_eq!(transparent, [0, 0, 0, 0]);
+  }
+
+  #[test]
+  fn test_to_png_writes_png_signature() {
+    let png = IconName::Heart.to_png(16, rgb(0xffffff), 2.0).unwrap();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+  }
+}
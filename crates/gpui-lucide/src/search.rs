@@ -0,0 +1,743 @@
+//! Fuzzy, ranked icon search.
+//!
+//! Queries follow the small grammar described on [`Query`]. Bare words are split into
+//! terms on `-` and `_`, and every term must match the icon's name or one of its
+//! keywords (aliases, synonyms, tags and categories). For each term the best of these
+//! matches
+//! counts, tried from strongest to weakest:
+//!
+//! 1. the whole text,
+//! 2. a prefix of the text,
+//! 3. a prefix of a later word, so `down` finds `arrow-down`,
+//! 4. a substring,
+//! 5. a subsequence starting at a word, so `dwn` finds `down`,
+//! 6. a word within a small edit distance, so `serach` finds `search`.
+//!
+//! Matches in the name count more than aliases, synonyms, tags and categories, in that
+//! order. Synonyms and translations come from [`Dictionary`] files registered with
+//! [`Synonyms`].
+//! Icons are ranked by the sum of their term scores, shorter names first on ties.
+
+mod query;
+mod synonyms;
+
+pub use query::*;
+pub use synonyms::*;
+
+use crate::{IconName, IconNamed};
+use std::ops::Range;
+use std::sync::OnceLock;
+
+/// Where a query term matched an icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchField {
+  /// The icon name.
+  Name,
+  /// A former name of the icon.
+  Alias,
+  /// A synonym or translation from a [`Dictionary`].
+  Synonym,
+  /// A search tag.
+  Tag,
+  /// A category.
+  Category,
+}
+
+impl MatchField {
+  /// Percentage of a match's score kept for this field.
+  fn weight(self) -> u32 {
+    match self {
+      Self::Name => 100,
+      Self::Alias => 90,
+      Self::Synonym => 80,
+      Self::Tag => 70,
+      Self::Category => 50,
+    }
+  }
+}
+
+/// How one query term matched an icon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TermMatch<'a> {
+  /// Where the term matched.
+  pub field: MatchField,
+  /// The lowercased name or keyword that matched.
+  pub text: &'a str,
+  /// Byte ranges of `text` covered by the term.
+  pub ranges: Vec<Range<usize>>,
+  /// Score of this term, already weighted by field.
+  pub score: u32,
+}
+
+/// An icon matching a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch<'a, I> {
+  /// The matching icon.
+  pub icon: &'a I,
+  /// Total score; higher is better.
+  pub score: u32,
+  /// Matches of the query terms, in query order. Category and tag filters appear
+  /// with a score of zero; excluded clauses do not appear.
+  pub terms: Vec<TermMatch<'a>>,
+}
+
+impl<I> SearchMatch<'_, I> {
+  /// Returns the sorted, merged byte ranges of the icon name matched by the query,
+  /// for highlighting.
+  pub fn name_ranges(&self) -> Vec<Range<usize>> {
+    let ranges = self
+      .terms
+      .iter()
+      .filter(|term| term.field == MatchField::Name)
+      .flat_map(|term| term.ranges.iter().cloned())
+      .collect();
+    merge_ranges(ranges)
+  }
+}
+
+struct Entry<I> {
+  icon: I,
+  name: String,
+  keywords: Vec<(MatchField, String)>,
+}
+
+impl<I> Entry<I> {
+  /// Adds a keyword unless the name or another keyword already has the same text.
+  fn add_keyword(&mut self, field: MatchField, keyword: &str) {
+    let keyword = keyword.to_lowercase();
+    let exists = self.name == keyword || self.keywords.iter().any(|(_, text)| *text == keyword);
+    if !exists {
+      self.keywords.push((field, keyword));
+    }
+  }
+}
+
+/// Searchable set of icons with their names and keywords.
+///
+/// Building the index lowercases all text once, so searching is cheap enough to run on
+/// every keystroke across the whole Lucide set.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::search::SearchIndex;
+///
+/// let index = SearchIndex::lucide();
+/// for result in index.search(&"arr dwn -circle".parse()?).iter().take(5) {
+///     println!("{} {:?}", result.icon, result.name_ranges());
+/// }
+/// ```
+pub struct SearchIndex<I> {
+  entries: Vec<Entry<I>>,
+}
+
+impl<I> Default for SearchIndex<I> {
+  fn default() -> Self {
+    Self {
+      entries: Vec::new(),
+    }
+  }
+}
+
+impl SearchIndex<IconName> {
+  /// Creates an index of every Lucide icon with its aliases, tags and categories, and
+  /// the synonyms of [`Dictionary::english`].
+  pub fn lucide() -> Self {
+    let mut index = Self::new();
+    for icon in IconName::all() {
+      let keywords = icon
+        .aliases()
+        .iter()
+        .map(|alias| (MatchField::Alias, *alias))
+        .chain(icon.tags().iter().map(|tag| (MatchField::Tag, *tag)))
+        .chain(
+          icon
+            .categories()
+            .iter()
+            .map(|category| (MatchField::Category, *category)),
+        );
+      index.insert(icon, icon.name(), keywords);
+    }
+    index.add_dictionary(&Dictionary::english());
+    index
+  }
+
+  /// Adds the synonyms registered for each of `locales`.
+  pub fn with_synonyms(mut self, synonyms: &Synonyms, locales: &[&str]) -> Self {
+    for locale in locales {
+      if let Some(dictionary) = synonyms.dictionary(locale) {
+        self.add_dictionary(dictionary);
+      }
+    }
+    self
+  }
+
+  /// Adds the terms of `dictionary` as synonyms.
+  pub fn add_dictionary(&mut self, dictionary: &Dictionary) {
+    for entry in &mut self.entries {
+      for term in dictionary.terms(entry.icon) {
+        entry.add_keyword(MatchField::Synonym, term);
+      }
+    }
+  }
+}
+
+impl<I: IconNamed> SearchIndex<I> {
+  /// Creates an index matching the icons by name only.
+  pub fn from_icons(icons: impl IntoIterator<Item = I>) -> Self {
+    let mut index = Self::new();
+    for icon in icons {
+      let name = icon.name();
+      index.insert(icon, name, std::iter::empty::<(MatchField, &str)>());
+    }
+    index
+  }
+}
+
+impl<I> SearchIndex<I> {
+  /// Creates an empty index.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds an icon with its name and keywords.
+  pub fn insert<S: AsRef<str>>(
+    &mut self,
+    icon: I,
+    name: &str,
+    keywords: impl IntoIterator<Item = (MatchField, S)>,
+  ) {
+    self.entries.push(Entry {
+      icon,
+      name: name.to_lowercase(),
+      keywords: keywords
+        .into_iter()
+        .map(|(field, keyword)| (field, keyword.as_ref().to_lowercase()))
+        .collect(),
+    });
+  }
+
+  /// Returns the number of icons in the index.
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  /// Returns true when the index holds no icons.
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  /// Returns the indexed icons in insertion order.
+  pub fn icons(&self) -> impl Iterator<Item = &I> {
+    self.entries.iter().map(|entry| &entry.icon)
+  }
+
+  /// Returns the icons matching `query`, best first.
+  ///
+  /// Queries made only of filters and exclusions keep insertion order, and an empty
+  /// query matches every icon.
+  pub fn search(&self, query: &Query) -> Vec<SearchMatch<'_, I>> {
+    let mut results: Vec<(usize, SearchMatch<'_, I>)> = self
+      .entries
+      .iter()
+      .filter_map(|entry| {
+        let mut terms = Vec::new();
+        for clause in query.clauses() {
+          match (
+            clause.negated,
+            match_clause(&clause.kind, entry, !clause.negated),
+          ) {
+            (false, Some(matches)) => terms.extend(matches),
+            (true, None) => {}
+            _ => return None,
+          }
+        }
+        let result = SearchMatch {
+          icon: &entry.icon,
+          score: terms.iter().map(|term| term.score).sum(),
+          terms,
+        };
+        Some((entry.name.len(), result))
+      })
+      .collect();
+
+    if query.is_ranked() {
+      results.sort_by(|(a_len, a), (b_len, b)| b.score.cmp(&a.score).then(a_len.cmp(b_len)));
+    }
+    results.into_iter().map(|(_, result)| result).collect()
+  }
+}
+
+/// Searches every Lucide icon, using an index built on first use.
+pub fn search_icons(query: &str) -> Result<Vec<SearchMatch<'static, IconName>>, ParseQueryError> {
+  static INDEX: OnceLock<SearchIndex<IconName>> = OnceLock::new();
+  let query = Query::parse(query)?;
+  Ok(INDEX.get_or_init(SearchIndex::lucide).search(&query))
+}
+
+/// Matches a clause against an entry, returning one match per term of the clause.
+///
+/// Fuzzy matching is turned off for exclusions, so `-circle` does not drop icons that
+/// only resemble the word.
+fn match_clause<'a, I>(
+  kind: &ClauseKind,
+  entry: &'a Entry<I>,
+  fuzzy: bool,
+) -> Option<Vec<TermMatch<'a>>> {
+  match kind {
+    ClauseKind::Term(term) => term
+      .split(['-', '_'])
+      .filter(|part| !part.is_empty())
+      .map(|part| best_match(entry, false, |text| match_text(part, text, fuzzy)))
+      .collect(),
+    ClauseKind::Literal { text, anchor } => {
+      let name_only = *anchor != Anchor::None;
+      best_match(entry, name_only, |candidate| {
+        match_literal(text, *anchor, candidate)
+      })
+      .map(|term| vec![term])
+    }
+    ClauseKind::Category(category) => match_keyword(entry, MatchField::Category, category),
+    ClauseKind::Tag(tag) => match_keyword(entry, MatchField::Tag, tag),
+  }
+}
+
+/// Finds the best match in the entry's name and, unless `name_only`, its keywords.
+fn best_match<'a, I>(
+  entry: &'a Entry<I>,
+  name_only: bool,
+  matcher: impl Fn(&str) -> Option<(u32, Vec<Range<usize>>)>,
+) -> Option<TermMatch<'a>> {
+  let keywords = entry.keywords.iter().filter(|_| !name_only);
+  std::iter::once((MatchField::Name, entry.name.as_str()))
+    .chain(keywords.map(|(field, keyword)| (*field, keyword.as_str())))
+    .filter_map(|(field, text)| {
+      let (score, ranges) = matcher(text)?;
+      Some(TermMatch {
+        field,
+        text,
+        ranges,
+        score: score * field.weight() / 100,
+      })
+    })
+    // Keep the earliest on ties, so the name wins over keywords.
+    .reduce(|best, term| if term.score > best.score { term } else { best })
+}
+
+/// Matches a category or tag filter, which must equal one of the entry's keywords.
+fn match_keyword<'a, I>(
+  entry: &'a Entry<I>,
+  field: MatchField,
+  value: &str,
+) -> Option<Vec<TermMatch<'a>>> {
+  let (_, text) = entry
+    .keywords
+    .iter()
+    .find(|(keyword_field, keyword)| *keyword_field == field && keyword == value)?;
+  let range = 0..text.len();
+  Some(vec![TermMatch {
+    field,
+    text,
+    ranges: vec![range],
+    score: 0,
+  }])
+}
+
+/// Scores `term` against `text`, returning the score and the matched byte ranges.
+/// Subsequence and typo matches are only tried when `fuzzy` is set.
+fn match_text(term: &str, text: &str, fuzzy: bool) -> Option<(u32, Vec<Range<usize>>)> {
+  if term.is_empty() {
+    return None;
+  }
+  let single = |range: Range<usize>| vec![range];
+  if text == term {
+    return Some((1000, single(0..text.len())));
+  }
+  if text.starts_with(term) {
+    return Some((800, single(0..term.len())));
+  }
+
+  let words = word_ranges(text);
+  for (index, word) in words.iter().enumerate().skip(1) {
+    if text[word.clone()].starts_with(term) {
+      let penalty = 10 * index.min(10) as u32;
+      return Some((600 - penalty, single(word.start..word.start + term.len())));
+    }
+  }
+
+  if let Some(start) = text.find(term) {
+    return Some((400, single(start..start + term.len())));
+  }
+  if !fuzzy {
+    return None;
+  }
+
+  if let Some((gaps, ranges)) = subsequence(term, text, &words) {
+    return Some((300 - 5 * gaps.min(40), ranges));
+  }
+
+  typo(term, text, &words).map(|(distance, range)| (200 - 50 * distance, single(range)))
+}
+
+/// Matches quoted or anchored text exactly, treating spaces and `_` like `-`.
+fn match_literal(literal: &str, anchor: Anchor, text: &str) -> Option<(u32, Vec<Range<usize>>)> {
+  // Both separators are one byte wide, so byte offsets stay valid.
+  let literal = literal.replace([' ', '_'], "-");
+  let normalized = text.replace([' ', '_'], "-");
+  let range = match anchor {
+    Anchor::None => normalized
+      .find(&literal)
+      .map(|start| start..start + literal.len()),
+    Anchor::Start => normalized.starts_with(&literal).then_some(0..literal.len()),
+    Anchor::End => normalized
+      .ends_with(&literal)
+      .then(|| text.len() - literal.len()..text.len()),
+    Anchor::Both => (normalized == literal).then_some(0..text.len()),
+  }?;
+
+  let score = if range.len() == text.len() {
+    1000
+  } else if range.start == 0 {
+    800
+  } else if range.end == text.len() {
+    700
+  } else {
+    400
+  };
+  Some((score, vec![range]))
+}
+
+/// Byte ranges of the words in `text`, split on `-` and whitespace.
+fn word_ranges(text: &str) -> Vec<Range<usize>> {
+  let mut words = Vec::new();
+  let mut start = None;
+  for (index, c) in text.char_indices() {
+    let is_separator = c == '-' || c.is_whitespace();
+    match (start, is_separator) {
+      (None, false) => start = Some(index),
+      (Some(word_start), true) => {
+        words.push(word_start..index);
+        start = None;
+      }
+      _ => {}
+    }
+  }
+  if let Some(word_start) = start {
+    words.push(word_start..text.len());
+  }
+  words
+}
+
+/// Matches the characters of `term` in order, starting at a word start. Returns the
+/// number of skipped characters and the merged matched ranges.
+fn subsequence(term: &str, text: &str, words: &[Range<usize>]) -> Option<(u32, Vec<Range<usize>>)> {
+  if term.chars().count() < 2 {
+    return None;
+  }
+  let first = term.chars().next()?;
+
+  words
+    .iter()
+    .filter(|word| text[word.start..].starts_with(first))
+    .filter_map(|word| {
+      let mut ranges = Vec::new();
+      let mut chars = text[word.start..].char_indices();
+      for c in term.chars() {
+        let (offset, matched) = chars.find(|(_, candidate)| *candidate == c)?;
+        let start = word.start + offset;
+        ranges.push(start..start + matched.len_utf8());
+      }
+      let span = text[ranges[0].start..ranges[ranges.len() - 1].end]
+        .chars()
+        .count();
+      let gaps = (span - ranges.len()) as u32;
+      Some((gaps, merge_ranges(ranges)))
+    })
+    .min_by_key(|(gaps, _)| *gaps)
+}
+
+/// Finds the word closest to `term` within the allowed edit distance. Returns the
+/// distance and the matched part of the word.
+fn typo(term: &str, text: &str, words: &[Range<usize>]) -> Option<(u32, Range<usize>)> {
+  let term: Vec<char> = term.chars().collect();
+  let allowed = match term.len() {
+    0..4 => return None,
+    4..8 => 1,
+    _ => 2,
+  };
+
+  words
+    .iter()
+    .filter_map(|word| {
+      let offsets: Vec<usize> = text[word.clone()]
+        .char_indices()
+        .map(|(offset, _)| word.start + offset)
+        .chain(std::iter::once(word.end))
+        .collect();
+      let chars: Vec<char> = text[word.clone()].chars().collect();
+
+      // Compare against prefixes around the term's length, so typos in the first
+      // letters of a long word still match.
+      (term.len() - 1..=term.len() + 1)
+        .filter(|len| *len <= chars.len())
+        .map(|len| (edit_distance(&term, &chars[..len]), len))
+        .min()
+        .filter(|(distance, _)| *distance <= allowed)
+        .map(|(distance, len)| (distance as u32, word.start..offsets[len]))
+    })
+    .min_by_key(|(distance, _)| *distance)
+}
+
+/// Levenshtein distance that also counts swapping two adjacent characters as one edit.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+  let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+  for (i, row) in rows.iter_mut().enumerate() {
+    row[0] = i;
+  }
+  for (j, cell) in rows[0].iter_mut().enumerate() {
+    *cell = j;
+  }
+
+  for i in 1..=a.len() {
+    for j in 1..=b.len() {
+      let cost = usize::from(a[i - 1] != b[j - 1]);
+      let mut distance = (rows[i - 1][j] + 1)
+        .min(rows[i][j - 1] + 1)
+        .min(rows[i - 1][j - 1] + cost);
+      if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+        distance = distance.min(rows[i - 2][j - 2] + 1);
+      }
+      rows[i][j] = distance;
+    }
+  }
+  rows[a.len()][b.len()]
+}
+
+fn merge_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+  ranges.sort_by_key(|range| range.start);
+  let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+  for range in ranges {
+    match merged.last_mut() {
+      Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+      _ => merged.push(range),
+    }
+  }
+  merged
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn index() -> SearchIndex<&'static str> {
+    let mut index = SearchIndex::new();
+    index.insert(
+      "trash-2",
+      "trash-2",
+      [(MatchField::Tag, "delete"), (MatchField::Category, "files")],
+    );
+    index.insert("delete", "delete", [(MatchField::Category, "text")]);
+    index.insert("file", "file", [(MatchField::Category, "files")]);
+    index.insert("eraser", "eraser", [(MatchField::Alias, "rubber")]);
+    index
+  }
+
+  fn search(index: &SearchIndex<&'static str>, query: &str) -> Vec<&'static str> {
+    let query = Query::parse(query).unwrap();
+    index
+      .search(&query)
+      .iter()
+      .map(|result| *result.icon)
+      .collect()
+  }
+
+  fn lucide(query: &str) -> Vec<IconName> {
+    let results = search_icons(query).unwrap();
+    results.iter().map(|result| *result.icon).collect()
+  }
+
+  fn first(query: &str) -> Option<IconName> {
+    lucide(query).first().copied()
+  }
+
+  #[test]
+  fn test_exact_name_ranks_first() {
+    assert_eq!(first("heart"), Some(IconName::Heart));
+    assert_eq!(first("arrow-down"), Some(IconName::ArrowDown));
+  }
+
+  #[test]
+  fn test_word_prefixes_and_subsequences() {
+    let results = search_icons("arr dwn").unwrap();
+    assert_eq!(*results[0].icon, IconName::ArrowDown);
+    assert_eq!(results[0].name_ranges(), vec![0..3, 6..7, 8..10]);
+    assert!(
+      results
+        .iter()
+        .any(|result| *result.icon == IconName::ArrowDownLeft)
+    );
+  }
+
+  #[test]
+  fn test_typos_are_tolerated() {
+    assert_eq!(first("serach"), Some(IconName::Search));
+    assert_eq!(edit_distance(&['a', 'b'], &['b', 'a']), 1);
+    assert!(lucide("xq").is_empty());
+  }
+
+  #[test]
+  fn test_every_term_must_match() {
+    assert!(lucide("heart zzzz").is_empty());
+  }
+
+  #[test]
+  fn test_empty_query_lists_everything_in_order() {
+    assert_eq!(
+      search(&index(), "  "),
+      vec!["trash-2", "delete", "file", "eraser"]
+    );
+  }
+
+  #[test]
+  fn test_keyword_matches() {
+    let index = index();
+
+    let results = index.search(&"rubber".parse().unwrap());
+    assert_eq!(*results[0].icon, "eraser");
+    assert_eq!(results[0].terms[0].field, MatchField::Alias);
+    assert!(results[0].name_ranges().is_empty());
+
+    let results = index.search(&"files".parse().unwrap());
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].terms[0].field, MatchField::Category);
+  }
+
+  #[test]
+  fn test_name_outranks_tag() {
+    let index = index();
+    let results = index.search(&"delete".parse().unwrap());
+    let icons: Vec<_> = results.iter().map(|result| *result.icon).collect();
+    assert_eq!(icons, vec!["delete", "trash-2"]);
+    assert_eq!(results[1].terms[0].field, MatchField::Tag);
+    assert_eq!(results[1].terms[0].ranges, vec![0..6]);
+  }
+
+  #[test]
+  fn test_lucide_tags_are_searched() {
+    let index = SearchIndex::lucide();
+    let results = index.search(&"garbage".parse().unwrap());
+    let trash = results
+      .iter()
+      .find(|result| *result.icon == IconName::Trash)
+      .unwrap();
+    assert_eq!(trash.terms[0].field, MatchField::Tag);
+    assert_eq!(trash.terms[0].text, "garbage");
+  }
+
+  #[test]
+  fn test_english_synonyms() {
+    let results = lucide("garbage");
+    assert!(results.contains(&IconName::Trash2));
+
+    let results = search_icons("hamburger").unwrap();
+    assert_eq!(*results[0].icon, IconName::Hamburger);
+    let menu = results
+      .iter()
+      .find(|result| *result.icon == IconName::Menu)
+      .unwrap();
+    assert_eq!(menu.terms[0].field, MatchField::Synonym);
+  }
+
+  #[test]
+  fn test_localized_synonyms() {
+    let mut german = Dictionary::new();
+    german.insert(IconName::Trash2, "Papierkorb");
+    german.insert(IconName::Trash2, "trash");
+    let mut synonyms = Synonyms::new();
+    synonyms.register("de", german);
+
+    let index = SearchIndex::lucide();
+    let query = Query::parse("papierkorb").unwrap();
+    assert!(index.search(&query).is_empty());
+
+    let index = index.with_synonyms(&synonyms, &["de-DE"]);
+    let results = index.search(&query);
+    assert_eq!(*results[0].icon, IconName::Trash2);
+    assert_eq!(results[0].terms[0].field, MatchField::Synonym);
+  }
+
+  #[test]
+  fn test_filters_and_exclusions() {
+    let index = index();
+    assert_eq!(search(&index, "cat:files"), vec!["trash-2", "file"]);
+    assert_eq!(search(&index, "cat:files -trash"), vec!["file"]);
+    assert_eq!(search(&index, "tag:delete"), vec!["trash-2"]);
+    assert_eq!(
+      search(&index, "-cat:files -tag:delete"),
+      vec!["delete", "eraser"]
+    );
+    assert!(search(&index, "cat:file").is_empty());
+  }
+
+  #[test]
+  fn test_filters_on_lucide_metadata() {
+    let results = lucide("cat:arrows -circle");
+    assert!(results.contains(&IconName::ArrowDown));
+    assert!(!results.contains(&IconName::CircleArrowDown));
+    assert!(
+      results
+        .iter()
+        .all(|icon| icon.categories().contains(&"arrows"))
+    );
+
+    let results = lucide("tag:garbage");
+    assert!(results.contains(&IconName::Trash2));
+    assert!(results.iter().all(|icon| icon.tags().contains(&"garbage")));
+  }
+
+  #[test]
+  fn test_exclusions_are_not_fuzzy() {
+    // `fiel` would fuzzily match `file`, but only exact matches exclude.
+    assert_eq!(search(&index(), "-fiel cat:files"), vec!["trash-2", "file"]);
+  }
+
+  #[test]
+  fn test_phrases_and_anchors() {
+    let index = index();
+    assert_eq!(search(&index, "^era"), vec!["eraser"]);
+    assert_eq!(search(&index, "ser$"), vec!["eraser"]);
+    assert_eq!(search(&index, "^file$"), vec!["file"]);
+    assert_eq!(search(&index, "\"trash 2\""), vec!["trash-2"]);
+    // Anchors only apply to names, quotes also to keywords.
+    assert!(search(&index, "^rubber").is_empty());
+    assert_eq!(search(&index, "\"rubber\""), vec!["eraser"]);
+
+    let results = lucide("^arrow down$ -big");
+    assert!(results.contains(&IconName::ArrowDown));
+    assert!(!results.contains(&IconName::ArrowBigDown));
+    assert!(results.iter().all(|icon| icon.name().starts_with("arrow")));
+    assert!(results.iter().all(|icon| icon.name().ends_with("down")));
+  }
+
+  #[test]
+  fn test_search_icons_reports_parse_errors() {
+    assert_eq!(
+      search_icons("cat:").unwrap_err(),
+      ParseQueryError::MissingValue {
+        filter: "cat".to_string(),
+        position: 0
+      }
+    );
+  }
+
+  #[test]
+  fn test_word_ranges() {
+    assert_eq!(word_ranges("arrow-down left"), vec![0..5, 6..10, 11..15]);
+    assert_eq!(word_ranges("-a-"), vec![1..2]);
+  }
+
+  #[test]
+  fn test_merge_ranges() {
+    assert_eq!(merge_ranges(vec![4..6, 0..2, 2..3, 5..8]), vec![0..3, 4..8]);
+  }
+}
@@ -0,0 +1,388 @@
+//! Parsing of search queries.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Where a literal must appear in the icon name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Anchor {
+  /// Anywhere in the name or a keyword.
+  None,
+  /// At the start of the name, written `^text`.
+  Start,
+  /// At the end of the name, written `text$`.
+  End,
+  /// The whole name, written `^text$`.
+  Both,
+}
+
+/// What a query clause matches.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClauseKind {
+  /// A bare word, matched fuzzily against names and keywords.
+  Term(String),
+  /// Quoted or anchored text, matched exactly.
+  Literal { text: String, anchor: Anchor },
+  /// A `cat:` or `category:` filter.
+  Category(String),
+  /// A `tag:` filter.
+  Tag(String),
+}
+
+/// One whitespace-separated part of a query.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Clause {
+  /// Excludes the icons the clause matches instead of requiring it, written `-clause`.
+  pub negated: bool,
+  /// What the clause matches.
+  pub kind: ClauseKind,
+}
+
+/// A parsed search query.
+///
+/// Queries are whitespace-separated clauses, all of which must hold:
+///
+/// - `arrow` matches names, aliases, tags and categories fuzzily.
+/// - `"arrow down"` matches the exact text; spaces also match `-` in names.
+/// - `^arrow` and `down$` anchor the text to the start or end of the name.
+/// - `cat:arrows` and `tag:direction` require a category or tag. Quote values with
+///   spaces, as in `tag:"left arrow"`.
+/// - `-circle` excludes icons matching the clause.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::search::Query;
+///
+/// let query: Query = "cat:arrows tag:direction -circle".parse()?;
+/// assert_eq!(query.clauses().len(), 3);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Query {
+  clauses: Vec<Clause>,
+}
+
+impl Query {
+  /// Parses a query.
+  pub fn parse(input: &str) -> Result<Self, ParseQueryError> {
+    let mut parser = Parser { input, position: 0 };
+    let mut clauses = Vec::new();
+    while let Some(clause) = parser.clause()? {
+      clauses.push(clause);
+    }
+    Ok(Self { clauses })
+  }
+
+  /// Returns the clauses in query order.
+  pub fn clauses(&self) -> &[Clause] {
+    &self.clauses
+  }
+
+  /// Returns true when the query has no clauses and so matches every icon.
+  pub fn is_empty(&self) -> bool {
+    self.clauses.is_empty()
+  }
+
+  /// Returns true when some clause ranks the results, rather than only filtering them.
+  pub(crate) fn is_ranked(&self) -> bool {
+    self.clauses.iter().any(|clause| {
+      !clause.negated
+        && matches!(
+          clause.kind,
+          ClauseKind::Term(_) | ClauseKind::Literal { .. }
+        )
+    })
+  }
+}
+
+impl FromStr for Query {
+  type Err = ParseQueryError;
+
+  fn from_str(input: &str) -> Result<Self, Self::Err> {
+    Self::parse(input)
+  }
+}
+
+/// Error returned for a query that does not follow the grammar of [`Query`].
+///
+/// Positions are byte offsets into the query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseQueryError {
+  /// A quote is never closed.
+  UnterminatedQuote { position: usize },
+  /// A filter such as `cat:` has no value.
+  MissingValue { filter: String, position: usize },
+  /// A `name:value` filter uses an unknown name.
+  UnknownFilter { filter: String, position: usize },
+  /// A clause has no text, such as a lone `-`, `^` or `""`.
+  EmptyClause { position: usize },
+}
+
+impl ParseQueryError {
+  /// Returns the byte offset where the error was found.
+  pub fn position(&self) -> usize {
+    match self {
+      Self::UnterminatedQuote { position }
+      | Self::MissingValue { position, .. }
+      | Self::UnknownFilter { position, .. }
+      | Self::EmptyClause { position } => *position,
+    }
+  }
+}
+
+impl fmt::Display for ParseQueryError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::UnterminatedQuote { position } => {
+        write!(f, "unterminated quote at position {}", position)
+      }
+      Self::MissingValue { filter, position } => {
+        write!(
+          f,
+          "missing value for `{}:` at position {}",
+          filter, position
+        )
+      }
+      Self::UnknownFilter { filter, position } => write!(
+        f,
+        "unknown filter `{}:` at position {}, expected `cat:` or `tag:`",
+        filter, position
+      ),
+      Self::EmptyClause { position } => write!(f, "empty search term at position {}", position),
+    }
+  }
+}
+
+impl std::error::Error for ParseQueryError {}
+
+struct Parser<'a> {
+  input: &'a str,
+  position: usize,
+}
+
+impl Parser<'_> {
+  fn peek(&self) -> Option<char> {
+    self.input[self.position..].chars().next()
+  }
+
+  fn eat(&mut self, expected: char) -> bool {
+    if self.peek() == Some(expected) {
+      self.position += expected.len_utf8();
+      true
+    } else {
+      false
+    }
+  }
+
+  /// Reads up to the next whitespace or quote.
+  fn word(&mut self) -> &str {
+    let start = self.position;
+    let rest = &self.input[start..];
+    let len = rest
+      .find(|c: char| c.is_whitespace() || c == '"')
+      .unwrap_or(rest.len());
+    self.position += len;
+    &self.input[start..self.position]
+  }
+
+  /// Reads a quoted or bare value, lowercased. Returns the value and whether it was
+  /// quoted.
+  fn value(&mut self) -> Result<(String, bool), ParseQueryError> {
+    let start = self.position;
+    if !self.eat('"') {
+      return Ok((self.word().to_lowercase(), false));
+    }
+
+    let rest = &self.input[self.position..];
+    let len = rest
+      .find('"')
+      .ok_or(ParseQueryError::UnterminatedQuote { position: start })?;
+    let value = rest[..len].to_lowercase();
+    self.position += len + 1;
+    Ok((value, true))
+  }
+
+  fn clause(&mut self) -> Result<Option<Clause>, ParseQueryError> {
+    let rest = &self.input[self.position..];
+    self.position += rest.len() - rest.trim_start().len();
+    let start = self.position;
+    if self.peek().is_none() {
+      return Ok(None);
+    }
+
+    let negated = self.eat('-');
+    let anchored_start = self.eat('^');
+
+    if !anchored_start && let Some(kind) = self.filter()? {
+      return Ok(Some(Clause { negated, kind }));
+    }
+
+    let (mut text, quoted) = self.value()?;
+    let anchored_end = if quoted {
+      self.eat('$')
+    } else if text.ends_with('$') {
+      text.pop();
+      true
+    } else {
+      false
+    };
+
+    if text.is_empty() {
+      return Err(ParseQueryError::EmptyClause { position: start });
+    }
+
+    let anchor = match (anchored_start, anchored_end) {
+      (false, false) => Anchor::None,
+      (true, false) => Anchor::Start,
+      (false, true) => Anchor::End,
+      (true, true) => Anchor::Both,
+    };
+    let kind = if quoted || anchor != Anchor::None {
+      ClauseKind::Literal { text, anchor }
+    } else {
+      ClauseKind::Term(text)
+    };
+    Ok(Some(Clause { negated, kind }))
+  }
+
+  /// Parses a `name:value` filter, leaving the position unchanged when there is none.
+  fn filter(&mut self) -> Result<Option<ClauseKind>, ParseQueryError> {
+    let start = self.position;
+    let rest = &self.input[start..];
+    let Some(colon) = rest.find(':') else {
+      return Ok(None);
+    };
+    let name = &rest[..colon];
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+      return Ok(None);
+    }
+
+    let name = name.to_ascii_lowercase();
+    self.position += colon + 1;
+    let (value, _) = self.value()?;
+    if value.is_empty() {
+      return Err(ParseQueryError::MissingValue {
+        filter: name,
+        position: start,
+      });
+    }
+
+    match name.as_str() {
+      "cat" | "category" => Ok(Some(ClauseKind::Category(value))),
+      "tag" => Ok(Some(ClauseKind::Tag(value))),
+      _ => Err(ParseQueryError::UnknownFilter {
+        filter: name,
+        position: start,
+      }),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn clause(negated: bool, kind: ClauseKind) -> Clause {
+    Clause { negated, kind }
+  }
+
+  fn literal(text: &str, anchor: Anchor) -> ClauseKind {
+    ClauseKind::Literal {
+      text: text.to_string(),
+      anchor,
+    }
+  }
+
+  #[test]
+  fn test_parse_filters_and_negation() {
+    let query = Query::parse("cat:Arrows  tag:direction -circle").unwrap();
+    assert_eq!(
+      query.clauses(),
+      [
+        clause(false, ClauseKind::Category("arrows".to_string())),
+        clause(false, ClauseKind::Tag("direction".to_string())),
+        clause(true, ClauseKind::Term("circle".to_string())),
+      ]
+    );
+    assert!(!query.is_ranked());
+  }
+
+  #[test]
+  fn test_parse_phrases_and_anchors() {
+    let query: Query = r#""arrow down" ^chevron left$ ^x$ "big"$ tag:"left arrow""#
+      .parse()
+      .unwrap();
+    assert_eq!(
+      query.clauses(),
+      [
+        clause(false, literal("arrow down", Anchor::None)),
+        clause(false, literal("chevron", Anchor::Start)),
+        clause(false, literal("left", Anchor::End)),
+        clause(false, literal("x", Anchor::Both)),
+        clause(false, literal("big", Anchor::End)),
+        clause(false, ClauseKind::Tag("left arrow".to_string())),
+      ]
+    );
+    assert!(query.is_ranked());
+  }
+
+  #[test]
+  fn test_hyphens_inside_terms_are_not_negation() {
+    let query = Query::parse("arrow-down").unwrap();
+    assert_eq!(
+      query.clauses(),
+      [clause(false, ClauseKind::Term("arrow-down".to_string()))]
+    );
+  }
+
+  #[test]
+  fn test_empty_query() {
+    assert!(Query::parse("").unwrap().is_empty());
+    assert!(Query::parse("   ").unwrap().is_empty());
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    assert_eq!(
+      Query::parse(r#"arrow "down"#),
+      Err(ParseQueryError::UnterminatedQuote { position: 6 })
+    );
+    assert_eq!(
+      Query::parse("cat: arrows"),
+      Err(ParseQueryError::MissingValue {
+        filter: "cat".to_string(),
+        position: 0
+      })
+    );
+    assert_eq!(
+      Query::parse("x -color:red"),
+      Err(ParseQueryError::UnknownFilter {
+        filter: "color".to_string(),
+        position: 3
+      })
+    );
+    assert_eq!(
+      Query::parse("arrow - down"),
+      Err(ParseQueryError::EmptyClause { position: 6 })
+    );
+    assert!(Query::parse("^").is_err());
+    assert!(Query::parse("$").is_err());
+    assert!(Query::parse(r#""""#).is_err());
+  }
+
+  #[test]
+  fn test_error_messages() {
+    assert_eq!(
+      ParseQueryError::UnknownFilter {
+        filter: "color".to_string(),
+        position: 3
+      }
+      .to_string(),
+      "unknown filter `color:` at position 3, expected `cat:` or `tag:`"
+    );
+    assert_eq!(
+      ParseQueryError::UnterminatedQuote { position: 6 }.to_string(),
+      "unterminated quote at position 6"
+    );
+  }
+}
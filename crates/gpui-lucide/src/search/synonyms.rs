@@ -0,0 +1,338 @@
+//! Synonyms and translated search terms for icons.
+
+use crate::IconName;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Curated English terms, merged with the Lucide tags by [`Dictionary::english`].
+const ENGLISH: &str = include_str!("../../data/synonyms-en.toml");
+
+/// Extra search terms for icons, such as synonyms or translations.
+///
+/// Files map icon names to one term or a list of terms. In TOML:
+///
+/// ```toml
+/// trash-2 = ["löschen", "papierkorb"]
+/// house = "startseite"
+/// ```
+///
+/// and the same as a JSON object:
+///
+/// ```json
+/// { "trash-2": ["löschen", "papierkorb"], "house": "startseite" }
+/// ```
+///
+/// Entries naming an icon that does not exist are skipped with a warning, so a
+/// dictionary written for a newer Lucide release still loads.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dictionary {
+  terms: HashMap<IconName, Vec<String>>,
+}
+
+/// One term or a list of terms, as written in dictionary files.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Terms {
+  One(String),
+  Many(Vec<String>),
+}
+
+impl Dictionary {
+  /// Creates an empty dictionary.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the English dictionary shipped with the crate: everyday words such as
+  /// "delete" for `trash-2`, plus every icon's Lucide tags.
+  pub fn english() -> Self {
+    let mut dictionary =
+      Self::from_toml(ENGLISH).expect("bundled English synonyms should be valid");
+    for icon in IconName::all() {
+      for tag in icon.tags() {
+        dictionary.insert(icon, *tag);
+      }
+    }
+    dictionary
+  }
+
+  /// Parses a dictionary from TOML.
+  pub fn from_toml(source: &str) -> Result<Self, DictionaryError> {
+    let entries: BTreeMap<String, Terms> = toml::from_str(source).map_err(DictionaryError::Toml)?;
+    Self::from_entries(entries)
+  }
+
+  /// Parses a dictionary from JSON.
+  pub fn from_json(source: &str) -> Result<Self, DictionaryError> {
+    let entries: BTreeMap<String, Terms> =
+      serde_json::from_str(source).map_err(DictionaryError::Json)?;
+    Self::from_entries(entries)
+  }
+
+  /// Reads a dictionary from a `.toml` or `.json` file.
+  pub fn load(path: impl AsRef<Path>) -> Result<Self, DictionaryError> {
+    let path = path.as_ref();
+    let extension = path.extension().and_then(|extension| extension.to_str());
+    let read = || std::fs::read_to_string(path).map_err(DictionaryError::Io);
+    match extension {
+      Some("toml") => Self::from_toml(&read()?),
+      Some("json") => Self::from_json(&read()?),
+      _ => Err(DictionaryError::UnsupportedFormat(path.to_path_buf())),
+    }
+  }
+
+  fn from_entries(entries: BTreeMap<String, Terms>) -> Result<Self, DictionaryError> {
+    let mut dictionary = Self::new();
+    for (name, terms) in entries {
+      let Some(icon) = IconName::from_name(&name) else {
+        log::warn!("skipping unknown icon name in dictionary: `{}`", name);
+        continue;
+      };
+      let terms = match terms {
+        Terms::One(term) => vec![term],
+        Terms::Many(terms) => terms,
+      };
+      for term in terms {
+        dictionary.insert(icon, term);
+      }
+    }
+    Ok(dictionary)
+  }
+
+  /// Adds a term for `icon`, ignoring case and duplicates.
+  pub fn insert(&mut self, icon: IconName, term: impl Into<String>) {
+    let term = term.into().trim().to_lowercase();
+    if term.is_empty() {
+      return;
+    }
+    let terms = self.terms.entry(icon).or_default();
+    if !terms.contains(&term) {
+      terms.push(term);
+    }
+  }
+
+  /// Adds every term of `other`.
+  pub fn extend(&mut self, other: Dictionary) {
+    for (icon, terms) in other.terms {
+      for term in terms {
+        self.insert(icon, term);
+      }
+    }
+  }
+
+  /// Returns the terms for `icon`.
+  pub fn terms(&self, icon: IconName) -> &[String] {
+    self.terms.get(&icon).map_or(&[], Vec::as_slice)
+  }
+
+  /// Returns the number of icons with terms.
+  pub fn len(&self) -> usize {
+    self.terms.len()
+  }
+
+  /// Returns true when no icon has terms.
+  pub fn is_empty(&self) -> bool {
+    self.terms.is_empty()
+  }
+}
+
+/// Dictionaries by locale, consulted by [`SearchIndex::with_synonyms`].
+///
+/// Locales are free-form tags such as `en` or `de-AT`. Looking up a regional locale
+/// that has no dictionary of its own falls back to its language, so `de-AT` uses `de`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::search::{Dictionary, SearchIndex, Synonyms};
+///
+/// let mut synonyms = Synonyms::new();
+/// synonyms.register("de", Dictionary::load("synonyms/de.toml")?);
+/// let index = SearchIndex::lucide().with_synonyms(&synonyms, &["de-AT"]);
+/// ```
+///
+/// [`SearchIndex::with_synonyms`]: crate::search::SearchIndex::with_synonyms
+#[derive(Debug, Clone, Default)]
+pub struct Synonyms {
+  dictionaries: HashMap<String, Dictionary>,
+}
+
+impl Synonyms {
+  /// Creates an empty set of dictionaries.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Registers a dictionary for `locale`, merging it with any already registered.
+  pub fn register(&mut self, locale: impl Into<String>, dictionary: Dictionary) {
+    let locale = locale.into().to_lowercase();
+    self
+      .dictionaries
+      .entry(locale)
+      .or_default()
+      .extend(dictionary);
+  }
+
+  /// Returns the dictionary for `locale`, falling back to its language.
+  pub fn dictionary(&self, locale: &str) -> Option<&Dictionary> {
+    let locale = locale.to_lowercase();
+    self.dictionaries.get(&locale).or_else(|| {
+      let (language, _) = locale.split_once(['-', '_'])?;
+      self.dictionaries.get(language)
+    })
+  }
+
+  /// Returns the registered locales, in no particular order.
+  pub fn locales(&self) -> impl Iterator<Item = &str> {
+    self.dictionaries.keys().map(String::as_str)
+  }
+}
+
+/// Error returned when a dictionary cannot be loaded.
+#[derive(Debug)]
+pub enum DictionaryError {
+  /// The file could not be read.
+  Io(io::Error),
+  /// The file is not valid TOML.
+  Toml(toml::de::Error),
+  /// The file is not valid JSON.
+  Json(serde_json::Error),
+  /// The file extension is neither `.toml` nor `.json`.
+  UnsupportedFormat(PathBuf),
+}
+
+impl fmt::Display for DictionaryError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Io(error) => write!(f, "failed to read dictionary: {}", error),
+      Self::Toml(error) => write!(f, "invalid TOML dictionary: {}", error),
+      Self::Json(error) => write!(f, "invalid JSON dictionary: {}", error),
+      Self::UnsupportedFormat(path) => write!(
+        f,
+        "unsupported dictionary format: {}, expected .toml or .json",
+        path.display()
+      ),
+    }
+  }
+}
+
+impl std::error::Error for DictionaryError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Self::Io(error) => Some(error),
+      Self::Toml(error) => Some(error),
+      Self::Json(error) => Some(error),
+      Self::UnsupportedFormat(_) => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_toml_and_json() {
+    let toml = Dictionary::from_toml(
+      r#"
+      trash-2 = ["Löschen", "papierkorb"]
+      house = "startseite"
+      "#,
+    )
+    .unwrap();
+    let json =
+      Dictionary::from_json(r#"{ "trash-2": ["löschen", "Papierkorb"], "house": "Startseite" }"#)
+        .unwrap();
+
+    assert_eq!(toml, json);
+    assert_eq!(toml.terms(IconName::Trash2), ["löschen", "papierkorb"]);
+    assert_eq!(toml.terms(IconName::House), ["startseite"]);
+    assert!(toml.terms(IconName::Heart).is_empty());
+    assert_eq!(toml.len(), 2);
+  }
+
+  #[test]
+  fn test_unknown_icons_are_skipped() {
+    let dictionary = Dictionary::from_toml(
+      r#"
+      not-an-icon = "x"
+      house = "startseite"
+      "#,
+    )
+    .unwrap();
+    assert_eq!(dictionary.len(), 1);
+    assert_eq!(dictionary.terms(IconName::House), ["startseite"]);
+    assert!(matches!(
+      Dictionary::from_json("[1, 2]"),
+      Err(DictionaryError::Json(_))
+    ));
+  }
+
+  #[test]
+  fn test_load_checks_extension() {
+    assert!(matches!(
+      Dictionary::load("synonyms.yaml"),
+      Err(DictionaryError::UnsupportedFormat(_))
+    ));
+    assert!(matches!(
+      Dictionary::load("does-not-exist.toml"),
+      Err(DictionaryError::Io(_))
+    ));
+  }
+
+  #[test]
+  fn test_english_dictionary() {
+    let english = Dictionary::english();
+    assert!(
+      english
+        .terms(IconName::Trash2)
+        .contains(&"delete".to_string())
+    );
+    assert!(english.terms(IconName::X).contains(&"close".to_string()));
+  }
+
+  #[test]
+  fn test_english_dictionary_includes_lucide_tags() {
+    // `emotion` is a Lucide tag of `heart` that the curated list does not have.
+    let curated = Dictionary::from_toml(ENGLISH).unwrap();
+    assert!(
+      !curated
+        .terms(IconName::Heart)
+        .contains(&"emotion".to_string())
+    );
+    assert!(
+      Dictionary::english()
+        .terms(IconName::Heart)
+        .contains(&"emotion".to_string())
+    );
+  }
+
+  #[test]
+  fn test_insert_ignores_case_and_duplicates() {
+    let mut dictionary = Dictionary::new();
+    dictionary.insert(IconName::Heart, "Like");
+    dictionary.insert(IconName::Heart, "like ");
+    dictionary.insert(IconName::Heart, " ");
+    assert_eq!(dictionary.terms(IconName::Heart), ["like"]);
+  }
+
+  #[test]
+  fn test_locales_fall_back_to_language() {
+    let mut german = Dictionary::new();
+    german.insert(IconName::House, "startseite");
+    let mut austrian = Dictionary::new();
+    austrian.insert(IconName::House, "heim");
+
+    let mut synonyms = Synonyms::new();
+    synonyms.register("de", german);
+    synonyms.register("DE", austrian);
+
+    let dictionary = synonyms.dictionary("de-AT").unwrap();
+    assert_eq!(dictionary.terms(IconName::House), ["startseite", "heim"]);
+    assert!(synonyms.dictionary("fr").is_none());
+    assert_eq!(synonyms.locales().collect::<Vec<_>>(), ["de"]);
+  }
+}
@@ -0,0 +1,225 @@
+//! Raw SVG markup for icons.
+
+use crate::{IconName, IconSize};
+use gpui::{Hsla, Rgba};
+
+/// Presentation options baked into the markup returned by [`SvgExt::svg_with`].
+///
+/// Options left unset keep the values from the source file (`currentColor`, a stroke
+/// width of 2 and a 24px box for Lucide icons).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{IconName, IconSize, SvgExt, SvgOptions};
+///
+/// let markup = IconName::Heart.svg_with(
+///     SvgOptions::new()
+///         .color(gpui::rgb(0xe94560))
+///         .stroke_width(1.5)
+///         .with_size(IconSize::Large),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SvgOptions {
+  color: Option<Hsla>,
+  stroke_width: Option<f32>,
+  size: Option<IconSize>,
+}
+
+impl SvgOptions {
+  /// Creates options that leave the markup unchanged.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Replaces `currentColor` with the given color.
+  pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+    self.color = Some(color.into());
+    self
+  }
+
+  /// Sets the stroke width, in viewBox units.
+  pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+    self.stroke_width = Some(stroke_width);
+    self
+  }
+
+  /// Sets the rendered width and height using predefined sizes.
+  pub fn with_size(mut self, size: IconSize) -> Self {
+    self.size = Some(size);
+    self
+  }
+
+  /// Applies the options to SVG markup.
+  pub fn apply(&self, markup: &str) -> String {
+    let mut markup = markup.to_string();
+
+    if let Some(color) = self.color {
+      let rgba = Rgba::from(color);
+      markup = markup.replace("currentColor", &hex_color(rgba));
+      if rgba.a < 1.0 {
+        markup = set_root_attribute(&markup, "opacity", &rgba.a.to_string());
+      }
+    }
+
+    if let Some(stroke_width) = self.stroke_width {
+      markup = set_root_attribute(&markup, "stroke-width", &stroke_width.to_string());
+    }
+
+    if let Some(size) = self.size {
+      let pixels = size.to_pixels().to_string();
+      markup = set_root_attribute(&markup, "width", &pixels);
+      markup = set_root_attribute(&markup, "height", &pixels);
+    }
+
+    markup
+  }
+}
+
+/// Extends `IconName` with markup customized through [`SvgOptions`].
+pub trait SvgExt {
+  /// Returns the SVG markup with the given options baked in.
+  fn svg_with(&self, options: SvgOptions) -> String;
+}
+
+impl SvgExt for IconName {
+  fn svg_with(&self, options: SvgOptions) -> String {
+    options.apply(self.svg())
+  }
+}
+
+pub(crate) fn hex_color(color: Rgba) -> String {
+  let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+  format!(
+    "#{:02x}{:02x}{:02x}",
+    channel(color.r),
+    channel(color.g),
+    channel(color.b)
+  )
+}
+
+/// Formats an SVG coordinate with at most three decimals.
+pub(crate) fn svg_number(value: f32) -> String {
+  // Adding zero turns -0 into 0.
+  format!("{}", (value * 1000.0).round() / 1000.0 + 0.0)
+}
+
+/// Sets an attribute on the root `<svg>` element, adding it when missing.
+///
+/// Only the root tag is touched so that child attributes such as a `<rect>`'s `width`
+/// are left alone.
+pub(crate) fn set_root_attribute(markup: &str, name: &str, value: &str) -> String {
+  let Some(tag_start) = markup.find("<svg") else {
+    return markup.to_string();
+  };
+  let Some(tag_len) = markup[tag_start..].find('>') else {
+    return markup.to_string();
+  };
+  let tag_end = tag_start + tag_len;
+  let tag = &markup[tag_start..tag_end];
+
+  let needle = format!("{}=\"", name);
+  let existing = tag.match_indices(&needle).find(|(index, _)| {
+    tag[..*index]
+      .chars()
+      .next_back()
+      .is_some_and(char::is_whitespace)
+  });
+
+  match existing {
+    Some((index, _)) => {
+      let value_start = tag_start + index + needle.len();
+      let value_end = value_start + markup[value_start..].find('"').unwrap_or(0);
+      format!(
+        "{}{}{}",
+        &markup[..value_start],
+        value,
+        &markup[value_end..]
+      )
+    }
+    None => {
+      let insert_at = if markup[..tag_end].ends_with('/') {
+        tag_end - 1
+      } else {
+        tag_end
+      };
+      format!(
+        "{} {}=\"{}\"{}",
+        markup[..insert_at].trim_end(),
+        name,
+        value,
+        &markup[insert_at..]
+      )
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use gpui::rgb;
+
+  #[test]
+  fn test_svg_returns_icon_markup() {
+    let markup = IconName::Heart.svg();
+    assert!(markup.contains("<svg"));
+    assert!(markup.contains("stroke=\"currentColor\""));
+  }
+
+  #[test]
+  fn test_svg_with_default_options_is_unchanged() {
+    assert_eq!(
+      IconName::Heart.svg_with(SvgOptions::new()),
+      IconName::Heart.svg()
+    );
+  }
+
+  #[test]
+  fn test_svg_with_color() {
+    let markup = IconName::Heart.svg_with(SvgOptions::new().color(rgb(0xe94560)));
+    assert!(markup.contains("stroke=\"#e94560\""));
+    assert!(!markup.contains("currentColor"));
+    assert!(!markup.contains("opacity"));
+  }
+
+  #[test]
+  fn test_svg_with_translucent_color_sets_opacity() {
+    let color = gpui::hsla(0.0, 1.0, 0.5, 0.5);
+    let markup = IconName::Heart.svg_with(SvgOptions::new().color(color));
+    assert!(markup.contains("opacity=\"0.5\""));
+  }
+
+  #[test]
+  fn test_svg_with_stroke_width() {
+    let markup = IconName::Heart.svg_with(SvgOptions::new().stroke_width(1.5));
+    assert!(markup.contains("stroke-width=\"1.5\""));
+    assert!(!markup.contains("stroke-width=\"2\""));
+  }
+
+  #[test]
+  fn test_svg_with_size_only_changes_root() {
+    let markup =
+      IconName::RectangleHorizontal.svg_with(SvgOptions::new().with_size(IconSize::XLarge));
+    let root_end = markup.find('>').unwrap();
+
+    assert!(markup[..root_end].contains("width=\"32\""));
+    assert!(markup[..root_end].contains("height=\"32\""));
+    assert!(markup[root_end..].contains("width=\"20\""));
+  }
+
+  #[test]
+  fn test_set_root_attribute_inserts_missing_attribute() {
+    let markup = set_root_attribute("<svg viewBox=\"0 0 24 24\"><path /></svg>", "width", "16");
+    assert_eq!(
+      markup,
+      "<svg viewBox=\"0 0 24 24\" width=\"16\"><path /></svg>"
+    );
+  }
+
+  #[test]
+  fn test_set_root_attribute_does_not_match_suffix() {
+    let markup = set_root_attribute("<svg stroke-width=\"2\">", "width", "16");
+    assert_eq!(markup, "<svg stroke-width=\"2\" width=\"16\">");
+  }
+}
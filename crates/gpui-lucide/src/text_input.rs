@@ -1,20 +1,166 @@
-use crate::{
-  Backspace, ClearSearch, Copy, Cut, Delete, DeleteToBeginningOfLine, DeleteToEndOfLine,
-  DeleteWordBackward, End, Home, Left, MoveWordLeft, MoveWordRight, Paste, Right, SelectAll,
-  SelectLeft, SelectRight, SelectToBeginningOfLine, SelectToEndOfLine, SelectWordLeft,
-  SelectWordRight, ShowCharacterPalette,
-};
+//! Single-line text input.
+
+use self::text_input_actions::*;
 use gpui::{
   App, Bounds, ClipboardItem, Context, CursorStyle, ElementId, ElementInputHandler, Entity,
-  EntityInputHandler, FocusHandle, Focusable, GlobalElementId, IntoElement, LayoutId, MouseButton,
-  MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad, Pixels, Point, ShapedLine, SharedString,
-  Style, TextRun, UTF16Selection, UnderlineStyle, Window, div, fill, hsla, point, prelude::*, px,
-  relative, rgb, rgba, size,
+  EntityInputHandler, EventEmitter, FocusHandle, Focusable, GlobalElementId, Hsla, IntoElement,
+  KeyBinding, LayoutId, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad,
+  Pixels, Point, ShapedLine, SharedString, Style, TextRun, UTF16Selection, UnderlineStyle, Window,
+  div, fill, hsla, point, prelude::*, px, relative, rgb, rgba, size,
 };
 use std::ops::Range;
+use std::rc::Rc;
 use unicode_segmentation::UnicodeSegmentation;
 
-pub struct SearchInput {
+/// Actions handled by [`TextInput`], in the `text_input` namespace.
+///
+/// They live in their own module because names such as `Copy` would shadow standard
+/// items in glob imports of the crate.
+pub mod text_input_actions {
+  gpui::actions!(
+    text_input,
+    [
+      Clear,
+      Submit,
+      Backspace,
+      Delete,
+      Left,
+      Right,
+      SelectLeft,
+      SelectRight,
+      SelectAll,
+      Home,
+      End,
+      MoveWordLeft,
+      MoveWordRight,
+      SelectWordLeft,
+      SelectWordRight,
+      SelectToBeginningOfLine,
+      SelectToEndOfLine,
+      DeleteWordBackward,
+      DeleteToBeginningOfLine,
+      DeleteToEndOfLine,
+      ShowCharacterPalette,
+      Paste,
+      Cut,
+      Copy
+    ]
+  );
+}
+
+/// Key context of a [`TextInput`], for scoping key bindings to it.
+pub const TEXT_INPUT_CONTEXT: &str = "TextInput";
+
+/// Binds the platform's usual editing keys to the [`text_input_actions`].
+///
+/// Call it once at startup. Bindings are scoped to [`TEXT_INPUT_CONTEXT`].
+pub fn bind_text_input_keys(cx: &mut App) {
+  let context = Some(TEXT_INPUT_CONTEXT);
+
+  cx.bind_keys([
+    KeyBinding::new("escape", Clear, context),
+    KeyBinding::new("enter", Submit, context),
+    KeyBinding::new("backspace", Backspace, context),
+    KeyBinding::new("delete", Delete, context),
+    KeyBinding::new("left", Left, context),
+    KeyBinding::new("right", Right, context),
+    KeyBinding::new("shift-left", SelectLeft, context),
+    KeyBinding::new("shift-right", SelectRight, context),
+    KeyBinding::new("home", Home, context),
+    KeyBinding::new("end", End, context),
+    KeyBinding::new("secondary-a", SelectAll, context),
+    KeyBinding::new("secondary-v", Paste, context),
+    KeyBinding::new("secondary-c", Copy, context),
+    KeyBinding::new("secondary-x", Cut, context),
+  ]);
+
+  #[cfg(target_os = "macos")]
+  cx.bind_keys([
+    KeyBinding::new("alt-backspace", DeleteWordBackward, context),
+    KeyBinding::new("cmd-backspace", DeleteToBeginningOfLine, context),
+    KeyBinding::new("cmd-delete", DeleteToEndOfLine, context),
+    KeyBinding::new("alt-left", MoveWordLeft, context),
+    KeyBinding::new("alt-right", MoveWordRight, context),
+    KeyBinding::new("cmd-left", Home, context),
+    KeyBinding::new("cmd-right", End, context),
+    KeyBinding::new("shift-alt-left", SelectWordLeft, context),
+    KeyBinding::new("shift-alt-right", SelectWordRight, context),
+    KeyBinding::new("shift-cmd-left", SelectToBeginningOfLine, context),
+    KeyBinding::new("shift-cmd-right", SelectToEndOfLine, context),
+    KeyBinding::new("ctrl-cmd-space", ShowCharacterPalette, context),
+  ]);
+
+  #[cfg(not(target_os = "macos"))]
+  cx.bind_keys([
+    KeyBinding::new("ctrl-backspace", DeleteWordBackward, context),
+    KeyBinding::new("ctrl-left", MoveWordLeft, context),
+    KeyBinding::new("ctrl-right", MoveWordRight, context),
+    KeyBinding::new("shift-ctrl-left", SelectWordLeft, context),
+    KeyBinding::new("shift-ctrl-right", SelectWordRight, context),
+    KeyBinding::new("shift-home", SelectToBeginningOfLine, context),
+    KeyBinding::new("shift-end", SelectToEndOfLine, context),
+  ]);
+}
+
+/// Events emitted by a [`TextInput`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextInputEvent {
+  /// The text changed, by editing or through [`TextInput::set_text`].
+  Changed(SharedString),
+  /// The submit action was triggered, by default with enter.
+  Submitted(SharedString),
+}
+
+/// Colors of a [`TextInput`]. The text itself uses the inherited text color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextInputStyle {
+  /// Color of the placeholder.
+  pub placeholder: Hsla,
+  /// Color of the cursor.
+  pub cursor: Hsla,
+  /// Background of the selection.
+  pub selection: Hsla,
+  /// Color of the text while the validator reports an error.
+  pub error: Hsla,
+}
+
+impl Default for TextInputStyle {
+  fn default() -> Self {
+    Self {
+      placeholder: rgb(0x8b8b8b).into(),
+      cursor: hsla(0.0, 0.0, 1.0, 0.95),
+      selection: rgba(0x3399ff40).into(),
+      error: rgb(0xef4444).into(),
+    }
+  }
+}
+
+type Validator = Rc<dyn Fn(&str) -> Result<(), SharedString>>;
+type InputFilter = Rc<dyn Fn(&str) -> bool>;
+
+/// A single-line text field with grapheme-aware editing, IME and clipboard support.
+///
+/// Create it as an entity and subscribe to [`TextInputEvent`] to react to edits. Call
+/// [`bind_text_input_keys`] once at startup for the default key bindings.
+///
+/// Two hooks check the content: an input filter rejects edits outright, while a
+/// validator accepts the edit but marks the text invalid and reports a message.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{TextInput, TextInputEvent};
+///
+/// let input = cx.new(|cx| TextInput::new(cx).with_placeholder("Search icons..."));
+/// cx.subscribe(&input, |this, _, event, cx| {
+///     if let TextInputEvent::Changed(text) = event {
+///         this.query = text.clone();
+///         cx.notify();
+///     }
+/// })
+/// .detach();
+/// ```
+pub struct TextInput {
   focus_handle: FocusHandle,
   content: SharedString,
   placeholder: SharedString,
@@ -24,38 +170,125 @@ pub struct SearchInput {
   last_layout: Option<ShapedLine>,
   last_bounds: Option<Bounds<Pixels>>,
   is_selecting: bool,
+  validator: Option<Validator>,
+  input_filter: Option<InputFilter>,
+  error: Option<SharedString>,
+  style: TextInputStyle,
 }
 
-impl SearchInput {
+impl TextInput {
+  /// Creates an empty input.
   pub fn new(cx: &mut Context<Self>) -> Self {
     Self {
       focus_handle: cx.focus_handle(),
       content: "".into(),
-      placeholder: "Type to search...".into(),
+      placeholder: "".into(),
       selected_range: 0..0,
       selection_reversed: false,
       marked_range: None,
       last_layout: None,
       last_bounds: None,
       is_selecting: false,
+      validator: None,
+      input_filter: None,
+      error: None,
+      style: TextInputStyle::default(),
     }
   }
 
+  /// Sets the text shown while the input is empty.
+  pub fn with_placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+    self.placeholder = placeholder.into();
+    self
+  }
+
+  /// Changes the text shown while the input is empty.
+  pub fn set_placeholder(&mut self, placeholder: impl Into<SharedString>, cx: &mut Context<Self>) {
+    self.placeholder = placeholder.into();
+    cx.notify();
+  }
+
+  /// Sets the colors of the input.
+  pub fn set_style(&mut self, style: TextInputStyle, cx: &mut Context<Self>) {
+    self.style = style;
+    cx.notify();
+  }
+
+  /// Returns the current text.
   pub fn text(&self) -> &str {
     self.content.as_ref()
   }
 
-  pub fn clear(&mut self, _: &ClearSearch, _window: &mut Window, cx: &mut Context<Self>) {
-    if self.content.is_empty() {
+  /// Replaces the text and moves the cursor to the end.
+  ///
+  /// Bypasses the input filter, but still runs the validator.
+  pub fn set_text(&mut self, text: impl Into<SharedString>, cx: &mut Context<Self>) {
+    let text = text.into();
+    if text == self.content {
       return;
     }
-    self.content = "".into();
-    self.selected_range = 0..0;
+    self.selected_range = text.len()..text.len();
     self.selection_reversed = false;
     self.marked_range = None;
+    self.content = text;
+    self.content_changed(cx);
+  }
+
+  /// Sets a validator run after every change.
+  ///
+  /// While it returns an error the text is drawn in the error color and the message is
+  /// available from [`TextInput::error`].
+  pub fn set_validator(
+    &mut self,
+    validator: impl Fn(&str) -> Result<(), SharedString> + 'static,
+    cx: &mut Context<Self>,
+  ) {
+    self.validator = Some(Rc::new(validator));
+    self.validate();
     cx.notify();
   }
 
+  /// Sets a filter that rejects any edit producing text it returns false for, for
+  /// example to only accept digits.
+  pub fn set_input_filter(&mut self, filter: impl Fn(&str) -> bool + 'static) {
+    self.input_filter = Some(Rc::new(filter));
+  }
+
+  /// Returns the validation error for the current text, if any.
+  pub fn error(&self) -> Option<&SharedString> {
+    self.error.as_ref()
+  }
+
+  /// Returns true when the validator accepts the current text.
+  pub fn is_valid(&self) -> bool {
+    self.error.is_none()
+  }
+
+  fn validate(&mut self) {
+    self.error = self
+      .validator
+      .as_ref()
+      .and_then(|validator| validator(&self.content).err());
+  }
+
+  fn accepts(&self, text: &str) -> bool {
+    self.input_filter.as_ref().is_none_or(|filter| filter(text))
+  }
+
+  fn content_changed(&mut self, cx: &mut Context<Self>) {
+    self.validate();
+    cx.emit(TextInputEvent::Changed(self.content.clone()));
+    cx.notify();
+  }
+
+  fn clear(&mut self, _: &Clear, _: &mut Window, cx: &mut Context<Self>) {
+    self.set_text("", cx);
+  }
+
+  fn submit(&mut self, _: &Submit, _: &mut Window, cx: &mut Context<Self>) {
+    cx.emit(TextInputEvent::Submitted(self.content.clone()));
+  }
+
   fn left(&mut self, _: &Left, _: &mut Window, cx: &mut Context<Self>) {
     if self.selected_range.is_empty() {
       self.move_to(self.previous_boundary(self.cursor_offset()), cx);
@@ -324,55 +557,23 @@ impl SearchInput {
   }
 
   fn previous_boundary(&self, offset: usize) -> usize {
-    self
-      .content
-      .grapheme_indices(true)
-      .rev()
-      .find_map(|(idx, _)| (idx < offset).then_some(idx))
-      .unwrap_or(0)
+    previous_grapheme_boundary(&self.content, offset)
   }
 
   fn next_boundary(&self, offset: usize) -> usize {
-    self
-      .content
-      .grapheme_indices(true)
-      .find_map(|(idx, _)| (idx > offset).then_some(idx))
-      .unwrap_or(self.content.len())
-  }
-
-  fn is_word_char(ch: char) -> bool {
-    ch.is_alphanumeric() || ch == '_'
+    next_grapheme_boundary(&self.content, offset)
   }
 
   fn previous_word_boundary(&self, offset: usize) -> usize {
-    if offset == 0 {
-      return 0;
-    }
-
-    let mut last = 0;
-    for (idx, segment) in self.content[..offset].split_word_bound_indices() {
-      if segment.chars().any(Self::is_word_char) {
-        last = idx;
-      }
-    }
-    last
+    previous_word_boundary(&self.content, offset)
   }
 
   fn next_word_boundary(&self, offset: usize) -> usize {
-    if offset >= self.content.len() {
-      return self.content.len();
-    }
-
-    for (idx, segment) in self.content[offset..].split_word_bound_indices() {
-      if segment.chars().any(Self::is_word_char) {
-        return offset + idx + segment.len();
-      }
-    }
-    self.content.len()
+    next_word_boundary(&self.content, offset)
   }
 }
 
-impl EntityInputHandler for SearchInput {
+impl EntityInputHandler for TextInput {
   fn text_for_range(
     &mut self,
     range_utf16: Range<usize>,
@@ -425,11 +626,15 @@ impl EntityInputHandler for SearchInput {
       .or(self.marked_range.clone())
       .unwrap_or(self.selected_range.clone());
 
-    self.content =
-      (self.content[0..range.start].to_owned() + new_text + &self.content[range.end..]).into();
+    let content = self.content[0..range.start].to_owned() + new_text + &self.content[range.end..];
+    if !self.accepts(&content) {
+      return;
+    }
+
+    self.content = content.into();
     self.selected_range = range.start + new_text.len()..range.start + new_text.len();
     self.marked_range.take();
-    cx.notify();
+    self.content_changed(cx);
   }
 
   fn replace_and_mark_text_in_range(
@@ -446,8 +651,12 @@ impl EntityInputHandler for SearchInput {
       .or(self.marked_range.clone())
       .unwrap_or(self.selected_range.clone());
 
-    self.content =
-      (self.content[0..range.start].to_owned() + new_text + &self.content[range.end..]).into();
+    let content = self.content[0..range.start].to_owned() + new_text + &self.content[range.end..];
+    if !self.accepts(&content) {
+      return;
+    }
+
+    self.content = content.into();
     if !new_text.is_empty() {
       self.marked_range = Some(range.start..range.start + new_text.len());
     } else {
@@ -459,7 +668,7 @@ impl EntityInputHandler for SearchInput {
       .map(|new_range| new_range.start + range.start..new_range.end + range.end)
       .unwrap_or_else(|| range.start + new_text.len()..range.start + new_text.len());
 
-    cx.notify();
+    self.content_changed(cx);
   }
 
   fn bounds_for_range(
@@ -498,8 +707,8 @@ impl EntityInputHandler for SearchInput {
   }
 }
 
-struct SearchTextElement {
-  input: Entity<SearchInput>,
+struct TextInputElement {
+  input: Entity<TextInput>,
 }
 
 struct PrepaintState {
@@ -508,7 +717,7 @@ struct PrepaintState {
   selection: Option<PaintQuad>,
 }
 
-impl IntoElement for SearchTextElement {
+impl IntoElement for TextInputElement {
   type Element = Self;
 
   fn into_element(self) -> Self::Element {
@@ -516,7 +725,7 @@ impl IntoElement for SearchTextElement {
   }
 }
 
-impl Element for SearchTextElement {
+impl Element for TextInputElement {
   type RequestLayoutState = ();
   type PrepaintState = PrepaintState;
 
@@ -556,8 +765,11 @@ impl Element for SearchTextElement {
     let cursor = input.cursor_offset();
     let style = window.text_style();
 
+    let input_style = input.style;
     let (display_text, text_color) = if content.is_empty() {
-      (input.placeholder.clone(), rgb(0x8b8b8b).into())
+      (input.placeholder.clone(), input_style.placeholder)
+    } else if input.error.is_some() {
+      (content, input_style.error)
     } else {
       (content, style.color)
     };
@@ -611,7 +823,7 @@ impl Element for SearchTextElement {
             point(bounds.left() + cursor_pos, bounds.top()),
             size(px(2.), bounds.bottom() - bounds.top()),
           ),
-          hsla(0.0, 0.0, 1.0, 0.95),
+          input_style.cursor,
         )),
       )
     } else {
@@ -627,7 +839,7 @@ impl Element for SearchTextElement {
               bounds.bottom(),
             ),
           ),
-          rgba(0x3399ff40),
+          input_style.selection,
         )),
         None,
       )
@@ -668,7 +880,7 @@ impl Element for SearchTextElement {
         window,
         cx,
       )
-      .expect("search input line paint should succeed");
+      .expect("text input line paint should succeed");
 
     if focus_handle.is_focused(window)
       && let Some(cursor) = prepaint.cursor.take()
@@ -683,14 +895,15 @@ impl Element for SearchTextElement {
   }
 }
 
-impl Render for SearchInput {
+impl Render for TextInput {
   fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
     div()
-      .id("search-input")
-      .key_context("SearchInput")
+      .id(("text-input", cx.entity_id()))
+      .key_context(TEXT_INPUT_CONTEXT)
       .track_focus(&self.focus_handle(cx))
       .cursor(CursorStyle::IBeam)
       .on_action(cx.listener(Self::clear))
+      .on_action(cx.listener(Self::submit))
       .on_action(cx.listener(Self::backspace))
       .on_action(cx.listener(Self::delete))
       .on_action(cx.listener(Self::delete_word_backward))
@@ -724,13 +937,84 @@ impl Render for SearchInput {
         div()
           .h(px(20.0))
           .w_full()
-          .child(SearchTextElement { input: cx.entity() }),
+          .child(TextInputElement { input: cx.entity() }),
       )
   }
 }
 
-impl Focusable for SearchInput {
+impl Focusable for TextInput {
   fn focus_handle(&self, _: &App) -> FocusHandle {
     self.focus_handle.clone()
   }
 }
+
+impl EventEmitter<TextInputEvent> for TextInput {}
+
+fn previous_grapheme_boundary(text: &str, offset: usize) -> usize {
+  text
+    .grapheme_indices(true)
+    .rev()
+    .find_map(|(idx, _)| (idx < offset).then_some(idx))
+    .unwrap_or(0)
+}
+
+fn next_grapheme_boundary(text: &str, offset: usize) -> usize {
+  text
+    .grapheme_indices(true)
+    .find_map(|(idx, _)| (idx > offset).then_some(idx))
+    .unwrap_or(text.len())
+}
+
+fn is_word_char(ch: char) -> bool {
+  ch.is_alphanumeric() || ch == '_'
+}
+
+fn previous_word_boundary(text: &str, offset: usize) -> usize {
+  if offset == 0 {
+    return 0;
+  }
+
+  let mut last = 0;
+  for (idx, segment) in text[..offset].split_word_bound_indices() {
+    if segment.chars().any(is_word_char) {
+      last = idx;
+    }
+  }
+  last
+}
+
+fn next_word_boundary(text: &str, offset: usize) -> usize {
+  if offset >= text.len() {
+    return text.len();
+  }
+
+  for (idx, segment) in text[offset..].split_word_bound_indices() {
+    if segment.chars().any(is_word_char) {
+      return offset + idx + segment.len();
+    }
+  }
+  text.len()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_grapheme_boundaries_keep_combining_marks() {
+    let text = "ae\u{301}b";
+    assert_eq!(next_grapheme_boundary(text, 1), 4);
+    assert_eq!(previous_grapheme_boundary(text, 4), 1);
+    assert_eq!(next_grapheme_boundary(text, 4), text.len());
+    assert_eq!(previous_grapheme_boundary(text, 0), 0);
+  }
+
+  #[test]
+  fn test_word_boundaries() {
+    let text = "arrow down-left";
+    assert_eq!(next_word_boundary(text, 0), 5);
+    assert_eq!(next_word_boundary(text, 5), 10);
+    assert_eq!(previous_word_boundary(text, text.len()), 11);
+    assert_eq!(previous_word_boundary(text, 6), 0);
+  }
+}
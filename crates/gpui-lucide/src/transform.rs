@@ -0,0 +1,374 @@
+//! Composable icon transformations.
+
+use crate::svg::svg_number;
+use gpui::{
+  App, Bounds, Global, Pixels, Point, Radians, SharedString, Size, Transformation, point, px,
+  radians, size,
+};
+use std::collections::HashSet;
+
+/// Scale, flip, rotation and translation applied to an icon.
+///
+/// Modifiers accumulate rather than replace each other: two `rotate` calls add their
+/// angles, scales multiply and translations add up. Regardless of call order, the
+/// icon is scaled (including flips) first, then rotated, both around the transform
+/// origin, and finally translated.
+///
+/// The origin defaults to the center of the icon and is given as a fraction of its
+/// bounds, so `(0.0, 0.0)` is the top-left corner and `(1.0, 1.0)` the bottom-right.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::IconTransform;
+///
+/// let transform = IconTransform::new()
+///     .flip_horizontal()
+///     .rotate(gpui::radians(std::f32::consts::FRAC_PI_4))
+///     .translate(gpui::point(gpui::px(0.), gpui::px(2.)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconTransform {
+  scale: Size<f32>,
+  rotation: f32,
+  translation: Point<Pixels>,
+  origin: Option<Point<f32>>,
+}
+
+impl Default for IconTransform {
+  fn default() -> Self {
+    Self {
+      scale: size(1.0, 1.0),
+      rotation: 0.0,
+      translation: Point::default(),
+      origin: None,
+    }
+  }
+}
+
+impl IconTransform {
+  /// Creates the identity transform.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds a rotation in radians, clockwise on screen.
+  pub fn rotate(mut self, radians: impl Into<Radians>) -> Self {
+    self.rotation += radians.into().0;
+    self
+  }
+
+  /// Scales uniformly by `factor`.
+  pub fn scale(self, factor: f32) -> Self {
+    self.scale_xy(factor, factor)
+  }
+
+  /// Scales each axis independently.
+  pub fn scale_xy(mut self, x: f32, y: f32) -> Self {
+    self.scale.width *= x;
+    self.scale.height *= y;
+    self
+  }
+
+  /// Mirrors the icon left to right.
+  pub fn flip_horizontal(self) -> Self {
+    self.scale_xy(-1.0, 1.0)
+  }
+
+  /// Mirrors the icon top to bottom.
+  pub fn flip_vertical(self) -> Self {
+    self.scale_xy(1.0, -1.0)
+  }
+
+  /// Moves the icon by `offset`, after scaling and rotating.
+  pub fn translate(mut self, offset: Point<Pixels>) -> Self {
+    self.translation = self.translation + offset;
+    self
+  }
+
+  /// Sets the point scaling and rotation happen around, as a fraction of the bounds.
+  pub fn origin(mut self, x: f32, y: f32) -> Self {
+    self.origin = Some(point(x, y));
+    self
+  }
+
+  /// Returns true when an origin other than the default center was set.
+  pub(crate) fn has_origin(&self) -> bool {
+    self.origin.is_some()
+  }
+
+  /// Returns true when the transform leaves the icon unchanged.
+  pub fn is_identity(&self) -> bool {
+    self.scale == size(1.0, 1.0) && self.rotation == 0.0 && self.translation == Point::default()
+  }
+
+  /// Maps a point inside `bounds` to where the transform moves it.
+  pub fn apply(&self, p: Point<Pixels>, bounds: Bounds<Pixels>) -> Point<Pixels> {
+    let origin = self.origin_point(bounds);
+    let x = f32::from(p.x - origin.x) * self.scale.width;
+    let y = f32::from(p.y - origin.y) * self.scale.height;
+    let (sin, cos) = self.rotation.sin_cos();
+    point(
+      origin.x + px(x * cos - y * sin) + self.translation.x,
+      origin.y + px(x * sin + y * cos) + self.translation.y,
+    )
+  }
+
+  /// Factor by which the transform scales lengths, used for stroke widths.
+  pub(crate) fn length_scale(&self) -> f32 {
+    (self.scale.width * self.scale.height).abs().sqrt()
+  }
+
+  /// Converts into a gpui `Transformation` for an element of the given size.
+  ///
+  /// gpui transforms around the element center, so a custom origin is turned into an
+  /// extra translation.
+  pub fn to_transformation(&self, element_size: Size<Pixels>) -> Transformation {
+    let offset = self.origin_offset(element_size);
+    Transformation::scale(self.scale)
+      .with_rotation(radians(self.rotation))
+      .with_translation(self.translation + offset)
+  }
+
+  /// Converts into an SVG `transform` attribute value in the units of `view_box`, for
+  /// an element of the given size. Returns `None` for the identity.
+  pub(crate) fn svg_transform(
+    &self,
+    view_box: [f32; 4],
+    element_size: Size<Pixels>,
+  ) -> Option<String> {
+    if self.is_identity() {
+      return None;
+    }
+
+    let [x, y, width, height] = view_box;
+    // Translations are in pixels; convert them to viewBox units.
+    let units_per_pixel = |units: f32, pixels: Pixels| {
+      let pixels = f32::from(pixels);
+      if pixels > 0.0 { units / pixels } else { 1.0 }
+    };
+    let translate_x = f32::from(self.translation.x) * units_per_pixel(width, element_size.width);
+    let translate_y = f32::from(self.translation.y) * units_per_pixel(height, element_size.height);
+    let origin = self.origin.unwrap_or(point(0.5, 0.5));
+    let origin_x = x + width * origin.x;
+    let origin_y = y + height * origin.y;
+
+    // SVG applies the list right to left: scale, rotate, then translate.
+    Some(format!(
+      "translate({} {}) rotate({}) scale({} {}) translate({} {})",
+      svg_number(origin_x + translate_x),
+      svg_number(origin_y + translate_y),
+      svg_number(self.rotation.to_degrees()),
+      svg_number(self.scale.width),
+      svg_number(self.scale.height),
+      svg_number(-origin_x),
+      svg_number(-origin_y)
+    ))
+  }
+
+  /// Translation that moves a transform around the center onto one around the origin.
+  fn origin_offset(&self, element_size: Size<Pixels>) -> Point<Pixels> {
+    let Some(origin) = self.origin else {
+      return Point::default();
+    };
+
+    // Origin relative to the center, before and after scaling and rotating.
+    let dx = (origin.x - 0.5) * f32::from(element_size.width);
+    let dy = (origin.y - 0.5) * f32::from(element_size.height);
+    let x = dx * self.scale.width;
+    let y = dy * self.scale.height;
+    let (sin, cos) = self.rotation.sin_cos();
+    point(px(dx - (x * cos - y * sin)), px(dy - (x * sin + y * cos)))
+  }
+
+  fn origin_point(&self, bounds: Bounds<Pixels>) -> Point<Pixels> {
+    let origin = self.origin.unwrap_or(point(0.5, 0.5));
+    point(
+      bounds.origin.x + bounds.size.width * origin.x,
+      bounds.origin.y + bounds.size.height * origin.y,
+    )
+  }
+}
+
+/// Paths already warned about by [`warn_unapplied_origin`].
+#[derive(Default)]
+struct OriginWarnings {
+  paths: HashSet<SharedString>,
+}
+
+impl Global for OriginWarnings {}
+
+/// Logs, once per path, that a transform origin could not be applied to an icon sized
+/// with relative lengths.
+pub(crate) fn warn_unapplied_origin(path: &SharedString, cx: &mut App) {
+  if cx
+    .default_global::<OriginWarnings>()
+    .paths
+    .insert(path.clone())
+  {
+    log::warn!(
+      "the transform origin is ignored for `{}`: it needs an absolute width and height, \
+       so the icon is transformed around its center",
+      path
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::f32::consts::{FRAC_PI_2, PI};
+
+  fn bounds() -> Bounds<Pixels> {
+    Bounds::new(point(px(0.0), px(0.0)), size(px(24.0), px(24.0)))
+  }
+
+  fn assert_near(actual: Point<Pixels>, x: f32, y: f32) {
+    assert!(
+      (f32::from(actual.x) - x).abs() < 1e-4 && (f32::from(actual.y) - y).abs() < 1e-4,
+      "expected ({}, {}), got {:?}",
+      x,
+      y,
+      actual
+    );
+  }
+
+  #[test]
+  fn test_default_is_identity() {
+    let transform = IconTransform::new();
+    assert!(transform.is_identity());
+    assert_near(transform.apply(point(px(3.0), px(5.0)), bounds()), 3.0, 5.0);
+  }
+
+  #[test]
+  fn test_rotations_accumulate() {
+    let transform = IconTransform::new()
+      .rotate(radians(FRAC_PI_2))
+      .rotate(radians(FRAC_PI_2));
+    assert_eq!(transform, IconTransform::new().rotate(radians(PI)));
+    assert_near(
+      transform.apply(point(px(24.0), px(12.0)), bounds()),
+      0.0,
+      12.0,
+    );
+  }
+
+  #[test]
+  fn test_scales_multiply_and_translations_add() {
+    let transform = IconTransform::new()
+      .scale(2.0)
+      .translate(point(px(1.0), px(0.0)))
+      .scale(0.25)
+      .translate(point(px(0.0), px(3.0)));
+
+    assert_eq!(
+      transform,
+      IconTransform::new()
+        .scale(0.5)
+        .translate(point(px(1.0), px(3.0)))
+    );
+  }
+
+  #[test]
+  fn test_flips_mirror_around_center() {
+    let flipped = IconTransform::new().flip_horizontal();
+    assert_near(flipped.apply(point(px(0.0), px(4.0)), bounds()), 24.0, 4.0);
+
+    let flipped = IconTransform::new().flip_vertical();
+    assert_near(flipped.apply(point(px(0.0), px(4.0)), bounds()), 0.0, 20.0);
+
+    let twice = IconTransform::new().flip_horizontal().flip_horizontal();
+    assert!(twice.is_identity());
+  }
+
+  #[test]
+  fn test_scale_applies_before_rotation() {
+    // Stretch along x, then turn a quarter: the stretch ends up vertical.
+    let transform = IconTransform::new()
+      .rotate(radians(FRAC_PI_2))
+      .scale_xy(2.0, 1.0);
+    assert_near(
+      transform.apply(point(px(18.0), px(12.0)), bounds()),
+      12.0,
+      24.0,
+    );
+  }
+
+  #[test]
+  fn test_translation_applies_last() {
+    let transform = IconTransform::new()
+      .translate(point(px(4.0), px(0.0)))
+      .rotate(radians(FRAC_PI_2));
+    // The offset is not rotated.
+    assert_near(
+      transform.apply(point(px(12.0), px(12.0)), bounds()),
+      16.0,
+      12.0,
+    );
+  }
+
+  #[test]
+  fn test_custom_origin() {
+    let transform = IconTransform::new().origin(0.0, 0.0).scale(0.5);
+    assert_near(transform.apply(point(px(0.0), px(0.0)), bounds()), 0.0, 0.0);
+    assert_near(
+      transform.apply(point(px(24.0), px(24.0)), bounds()),
+      12.0,
+      12.0,
+    );
+  }
+
+  #[test]
+  fn test_to_svg_uses_view_box_units() {
+    assert_eq!(
+      IconTransform::new().svg_transform([0.0, 0.0, 24.0, 24.0], size(px(48.), px(48.))),
+      None
+    );
+
+    // 12px on a 48px icon is 6 units of a 24-unit viewBox.
+    let transform = IconTransform::new()
+      .rotate(radians(FRAC_PI_2))
+      .flip_horizontal()
+      .translate(point(px(12.0), px(0.0)));
+    assert_eq!(
+      transform
+        .svg_transform([0.0, 0.0, 24.0, 24.0], size(px(48.), px(48.)))
+        .as_deref(),
+      Some("translate(18 12) rotate(90) scale(-1 1) translate(-12 -12)")
+    );
+
+    let transform = IconTransform::new().origin(0.0, 1.0).scale(0.5);
+    assert_eq!(
+      transform
+        .svg_transform([0.0, 0.0, 24.0, 24.0], size(px(24.), px(24.)))
+        .as_deref(),
+      Some("translate(0 24) rotate(0) scale(0.5 0.5) translate(0 -24)")
+    );
+  }
+
+  #[test]
+  fn test_origin_offset_matches_apply() {
+    let transform = IconTransform::new()
+      .origin(0.0, 1.0)
+      .rotate(radians(FRAC_PI_2))
+      .scale(0.5);
+    let offset = transform.origin_offset(size(px(24.0), px(24.0)));
+
+    // Transforming around the center and then shifting by the offset must land where
+    // transforming around the origin does.
+    let around_center = IconTransform {
+      origin: None,
+      ..transform
+    };
+    let p = point(px(6.0), px(2.0));
+    let expected = transform.apply(p, bounds());
+    let actual = around_center.apply(p, bounds()) + offset;
+    assert_near(actual, f32::from(expected.x), f32::from(expected.y));
+  }
+
+  #[test]
+  fn test_length_scale() {
+    assert_eq!(IconTransform::new().scale(2.0).length_scale(), 2.0);
+    assert_eq!(IconTransform::new().flip_horizontal().length_scale(), 1.0);
+  }
+}
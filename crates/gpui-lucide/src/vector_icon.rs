@@ -0,0 +1,512 @@
+//! Icon element that strokes its geometry with gpui paths.
+
+use crate::icon_fit::{aspect_ratio, fit_scale};
+use crate::{
+  IconColor, IconFit, IconName, IconNamed, IconSize, IconTransform, PathCommand, VectorData,
+  VectorPoint, resolve_color,
+};
+use gpui::{
+  App, Bounds, Global, Hsla, IntoElement, PathBuilder, PathStyle, Pixels, Point, Radians,
+  RenderOnce, SharedString, Size, StyleRefinement, Styled, Window, canvas, point, prelude::*, px,
+};
+use lyon::tessellation::{LineCap, LineJoin, StrokeOptions};
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Icon geometry, either generated at build time or parsed from an asset.
+#[derive(Debug, Clone)]
+pub(crate) enum Geometry {
+  Static(&'static VectorData),
+  Shared(Arc<VectorData>),
+}
+
+impl Deref for Geometry {
+  type Target = VectorData;
+
+  fn deref(&self) -> &VectorData {
+    match self {
+      Geometry::Static(data) => data,
+      Geometry::Shared(data) => data,
+    }
+  }
+}
+
+/// Geometry parsed from runtime asset paths, keyed by path.
+#[derive(Default)]
+struct VectorCache {
+  entries: HashMap<SharedString, Option<Arc<VectorData>>>,
+}
+
+impl Global for VectorCache {}
+
+/// Resolves the geometry of an icon path.
+///
+/// Bundled Lucide icons use the geometry generated at build time, other paths are
+/// loaded from the app's `AssetSource` once and cached. Returns `None` when the asset
+/// is missing or is not valid SVG.
+pub(crate) fn resolve_geometry(path: &SharedString, cx: &mut App) -> Option<Geometry> {
+  if let Some(icon) = IconName::from_path(path) {
+    return Some(Geometry::Static(icon.vector()));
+  }
+
+  if let Some(entry) = cx.default_global::<VectorCache>().entries.get(path) {
+    return entry.clone().map(Geometry::Shared);
+  }
+
+  let data = cx
+    .asset_source()
+    .load(path)
+    .ok()
+    .flatten()
+    .and_then(|bytes| VectorData::parse(std::str::from_utf8(&bytes).ok()?).ok())
+    .map(Arc::new);
+
+  cx.default_global::<VectorCache>()
+    .entries
+    .insert(path.clone(), data.clone());
+  data.map(Geometry::Shared)
+}
+
+#[derive(Debug, Clone)]
+enum VectorSource {
+  Geometry(Geometry),
+  Path(SharedString),
+}
+
+/// An icon painted as vector paths instead of a rasterized SVG texture.
+///
+/// `Icon` renders through gpui's `svg()` element, which caches a texture per size and
+/// color. `VectorIcon` strokes the geometry parsed at build time with
+/// `window.paint_path`, so it stays crisp under any transform and avoids a texture per
+/// variant when many icons are drawn.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use gpui_lucide::{IconName, IconSize, VectorIcon};
+///
+/// let icon = VectorIcon::new(IconName::Heart)
+///     .color(gpui::rgb(0xe94560))
+///     .stroke_width(1.5)
+///     .with_size(IconSize::Large);
+/// ```
+#[derive(IntoElement, Clone)]
+pub struct VectorIcon {
+  source: VectorSource,
+  pub(crate) color: Option<Hsla>,
+  pub(crate) color_token: Option<IconColor>,
+  /// Background and target ratio the color must contrast with.
+  pub(crate) contrast: Option<(Hsla, f32)>,
+  pub(crate) size: Option<IconSize>,
+  pub(crate) fit: Option<IconFit>,
+  pub(crate) stroke_width: Option<f32>,
+  pub(crate) transform: IconTransform,
+  /// Drawn fraction of each element, used by draw-on animations.
+  pub(crate) progress: Option<Arc<[f32]>>,
+  pub(crate) custom_style: StyleRefinement,
+}
+
+impl VectorIcon {
+  /// Creates a vector icon from a Lucide icon.
+  pub fn new(icon: IconName) -> Self {
+    Self::from_source(VectorSource::Geometry(Geometry::Static(icon.vector())))
+  }
+
+  /// Creates a vector icon from any type implementing `IconNamed`.
+  ///
+  /// The SVG is loaded from the app's `AssetSource` and parsed the first time it is
+  /// rendered.
+  pub fn from_named(icon: impl IconNamed) -> Self {
+    Self::from_path(icon.path())
+  }
+
+  /// Creates a vector icon from an SVG asset path.
+  pub fn from_path(path: impl Into<SharedString>) -> Self {
+    Self::from_source(VectorSource::Path(path.into()))
+  }
+
+  /// Creates a vector icon from already parsed geometry.
+  pub fn from_data(data: Arc<VectorData>) -> Self {
+    Self::from_source(VectorSource::Geometry(Geometry::Shared(data)))
+  }
+
+  /// Returns the asset path when the geometry has not been resolved yet.
+  pub(crate) fn path(&self) -> Option<&SharedString> {
+    match &self.source {
+      VectorSource::Path(path) => Some(path),
+      VectorSource::Geometry(_) => None,
+    }
+  }
+
+  /// Replaces the source with resolved geometry.
+  pub(crate) fn geometry(mut self, geometry: Geometry) -> Self {
+    self.source = VectorSource::Geometry(geometry);
+    self
+  }
+
+  /// Returns the number of drawable elements, when the geometry is known.
+  pub(crate) fn element_count(&self) -> Option<usize> {
+    match &self.source {
+      VectorSource::Geometry(geometry) => Some(geometry.elements.len()),
+      VectorSource::Path(_) => None,
+    }
+  }
+
+  fn from_source(source: VectorSource) -> Self {
+    Self {
+      source,
+      color: None,
+      color_token: None,
+      contrast: None,
+      size: None,
+      fit: None,
+      stroke_width: None,
+      transform: IconTransform::default(),
+      progress: None,
+      custom_style: StyleRefinement::default(),
+    }
+  }
+
+  /// Sets the icon color, replacing any color token.
+  pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+    self.color = Some(color.into());
+    self.color_token = None;
+    self
+  }
+
+  /// Sets the icon size using predefined sizes.
+  pub fn with_size(mut self, size: IconSize) -> Self {
+    self.size = Some(size);
+    self
+  }
+
+  /// Sets how the drawing fills a box whose aspect ratio differs from its viewBox.
+  ///
+  /// Defaults to [`IconFit::Contain`]. The fit is computed from the painted bounds, so
+  /// unlike [`Icon::fit`](crate::Icon::fit) it also applies to relative sizes.
+  pub fn fit(mut self, fit: IconFit) -> Self {
+    self.fit = Some(fit);
+    self
+  }
+
+  /// Overrides the stroke width, in viewBox units.
+  pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+    self.stroke_width = Some(stroke_width);
+    self
+  }
+
+  /// Rotates the icon by the given angle in radians, adding to any earlier rotation.
+  pub fn rotate(mut self, radians: impl Into<Radians>) -> Self {
+    self.transform = self.transform.rotate(radians);
+    self
+  }
+
+  /// Replaces the icon transform.
+  ///
+  /// Strokes are transformed along with the geometry, so they stay sharp at any
+  /// scale.
+  pub fn transform(mut self, transform: IconTransform) -> Self {
+    self.transform = transform;
+    self
+  }
+}
+
+impl Styled for VectorIcon {
+  fn style(&mut self) -> &mut StyleRefinement {
+    &mut self.custom_style
+  }
+}
+
+impl RenderOnce for VectorIcon {
+  fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+    let color = resolve_color(self.color, self.color_token, self.contrast, window, cx);
+    let text_size = window.text_style().font_size.to_pixels(window.rem_size());
+    let has_custom_size =
+      self.custom_style.size.width.is_some() || self.custom_style.size.height.is_some();
+
+    let geometry = match self.source {
+      VectorSource::Geometry(geometry) => Some(geometry),
+      VectorSource::Path(path) => resolve_geometry(&path, cx),
+    };
+    let stroke_width = self.stroke_width;
+    let icon_transform = self.transform;
+    let fit = self.fit;
+    let progress = self.progress;
+
+    let mut base = canvas(
+      |_, _, _| {},
+      move |bounds, _, window, _| {
+        let Some(data) = geometry else {
+          return;
+        };
+        let icon_transform = fit_transform(icon_transform, data.view_box, bounds.size, fit);
+        let transform = ViewBoxTransform::new(data.view_box, bounds, icon_transform);
+        let stroke_width = stroke_width.unwrap_or(data.stroke_width);
+        paint_vector(
+          &data,
+          &transform,
+          color,
+          stroke_width,
+          progress.as_deref(),
+          window,
+        );
+      },
+    );
+    *base.style() = self.custom_style;
+
+    base
+      .flex_none()
+      .flex_shrink_0()
+      .when(!has_custom_size && self.size.is_none(), |this| {
+        this.size(text_size)
+      })
+      .when_some(self.size, |this, size| {
+        this.size(gpui::rems(size.to_rems()))
+      })
+  }
+}
+
+/// Maps viewBox coordinates into element bounds, centering the drawing and keeping
+/// its aspect ratio, then applies the icon transform.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ViewBoxTransform {
+  origin: VectorPoint,
+  scale: f32,
+  offset: VectorPoint,
+  bounds: Bounds<Pixels>,
+  transform: IconTransform,
+}
+
+impl ViewBoxTransform {
+  pub(crate) fn new(view_box: [f32; 4], bounds: Bounds<Pixels>, transform: IconTransform) -> Self {
+    let width = f32::from(bounds.size.width);
+    let height = f32::from(bounds.size.height);
+    let scale = (width / view_box[2]).min(height / view_box[3]);
+    let left = f32::from(bounds.origin.x);
+    let top = f32::from(bounds.origin.y);
+
+    Self {
+      origin: [view_box[0], view_box[1]],
+      scale,
+      offset: [
+        left + (width - view_box[2] * scale) / 2.0,
+        top + (height - view_box[3] * scale) / 2.0,
+      ],
+      bounds,
+      transform,
+    }
+  }
+
+  /// Converts a length in viewBox units into pixels.
+  pub(crate) fn length(&self, length: f32) -> Pixels {
+    px(length * self.scale * self.transform.length_scale())
+  }
+
+  pub(crate) fn apply(&self, p: VectorPoint) -> Point<Pixels> {
+    let x = self.offset[0] + (p[0] - self.origin[0]) * self.scale;
+    let y = self.offset[1] + (p[1] - self.origin[1]) * self.scale;
+    self.transform.apply(point(px(x), px(y)), self.bounds)
+  }
+}
+
+/// Adds the scale that turns the contained drawing of [`ViewBoxTransform`] into one
+/// fitted with `fit`.
+fn fit_transform(
+  transform: IconTransform,
+  view_box: [f32; 4],
+  frame: Size<Pixels>,
+  fit: Option<IconFit>,
+) -> IconTransform {
+  let (Some(fit), Some(aspect)) = (fit, aspect_ratio(view_box)) else {
+    return transform;
+  };
+  if fit == IconFit::Contain {
+    return transform;
+  }
+  let (x, y) = fit_scale(aspect, frame, fit);
+  let (contain_x, contain_y) = fit_scale(aspect, frame, IconFit::Contain);
+  transform.scale_xy(x / contain_x, y / contain_y)
+}
+
+/// Builds a stroke with Lucide's round caps and joins.
+pub(crate) fn stroke_builder(width: Pixels) -> PathBuilder {
+  let mut builder = PathBuilder::stroke(width);
+  builder.style = PathStyle::Stroke(
+    StrokeOptions::default()
+      .with_line_width(f32::from(width))
+      .with_line_cap(LineCap::Round)
+      .with_line_join(LineJoin::Round),
+  );
+  builder
+}
+
+pub(crate) fn append_commands(
+  builder: &mut PathBuilder,
+  commands: &[PathCommand],
+  transform: &ViewBoxTransform,
+) {
+  for command in commands {
+    match *command {
+      PathCommand::MoveTo(to) => builder.move_to(transform.apply(to)),
+      PathCommand::LineTo(to) => builder.line_to(transform.apply(to)),
+      PathCommand::CubicTo { ctrl1, ctrl2, to } => builder.cubic_bezier_to(
+        transform.apply(to),
+        transform.apply(ctrl1),
+        transform.apply(ctrl2),
+      ),
+      PathCommand::Close => builder.close(),
+    }
+  }
+}
+
+/// Paints every element, trimming each to its entry in `progress` when given.
+pub(crate) fn paint_vector(
+  data: &VectorData,
+  transform: &ViewBoxTransform,
+  color: Hsla,
+  stroke_width: f32,
+  progress: Option<&[f32]>,
+  window: &mut Window,
+) {
+  for (index, element) in data.elements.iter().enumerate() {
+    let element_progress =
+      progress.map_or(1.0, |progress| progress.get(index).copied().unwrap_or(1.0));
+    let commands = if element_progress < 1.0 {
+      element.trimmed(element_progress).into()
+    } else {
+      element.commands.clone()
+    };
+    if commands.is_empty() {
+      continue;
+    }
+
+    // Fills appear once the outline is complete.
+    if element.filled && element_progress >= 1.0 {
+      let mut builder = PathBuilder::fill();
+      append_commands(&mut builder, &commands, transform);
+      if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+      }
+    }
+
+    let mut builder = stroke_builder(transform.length(stroke_width));
+    append_commands(&mut builder, &commands, transform);
+    if let Ok(path) = builder.build() {
+      window.paint_path(path, color);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use gpui::{rgb, size};
+
+  fn bounds(x: f32, y: f32, width: f32, height: f32) -> Bounds<Pixels> {
+    Bounds::new(point(px(x), px(y)), size(px(width), px(height)))
+  }
+
+  #[test]
+  fn test_new_uses_generated_geometry() {
+    let icon = VectorIcon::new(IconName::Heart);
+    let VectorSource::Geometry(geometry) = icon.source else {
+      panic!("expected generated geometry");
+    };
+    assert_eq!(&*geometry, IconName::Heart.vector());
+  }
+
+  #[test]
+  fn test_from_named_defers_to_asset_path() {
+    let icon = VectorIcon::from_named(IconName::Heart);
+    assert!(matches!(icon.source, VectorSource::Path(path) if path.as_ref() == "icons/heart.svg"));
+  }
+
+  #[test]
+  fn test_builder_methods() {
+    let icon = VectorIcon::new(IconName::Heart)
+      .color(rgb(0xff0000))
+      .with_size(IconSize::Large)
+      .stroke_width(1.5);
+
+    assert!(icon.color.is_some());
+    assert_eq!(icon.size, Some(IconSize::Large));
+    assert_eq!(icon.stroke_width, Some(1.5));
+  }
+
+  #[test]
+  fn test_view_box_transform_scales_into_bounds() {
+    let transform = ViewBoxTransform::new(
+      [0.0, 0.0, 24.0, 24.0],
+      bounds(10.0, 20.0, 48.0, 48.0),
+      IconTransform::default(),
+    );
+
+    assert_eq!(transform.apply([0.0, 0.0]), point(px(10.0), px(20.0)));
+    assert_eq!(transform.apply([24.0, 24.0]), point(px(58.0), px(68.0)));
+    assert_eq!(transform.length(2.0), px(4.0));
+  }
+
+  #[test]
+  fn test_view_box_transform_centers_non_square_bounds() {
+    let transform = ViewBoxTransform::new(
+      [0.0, 0.0, 24.0, 24.0],
+      bounds(0.0, 0.0, 48.0, 24.0),
+      IconTransform::default(),
+    );
+
+    assert_eq!(transform.apply([0.0, 0.0]), point(px(12.0), px(0.0)));
+  }
+
+  #[test]
+  fn test_view_box_transform_rotates_around_center() {
+    let transform = ViewBoxTransform::new(
+      [0.0, 0.0, 24.0, 24.0],
+      bounds(0.0, 0.0, 24.0, 24.0),
+      IconTransform::new().rotate(Radians(std::f32::consts::FRAC_PI_2)),
+    );
+    let rotated = transform.apply([24.0, 12.0]);
+
+    assert!((f32::from(rotated.x) - 12.0).abs() < 1e-4);
+    assert!((f32::from(rotated.y) - 24.0).abs() < 1e-4);
+  }
+
+  #[test]
+  fn test_view_box_transform_scales_strokes_with_transform() {
+    let transform = ViewBoxTransform::new(
+      [0.0, 0.0, 24.0, 24.0],
+      bounds(0.0, 0.0, 24.0, 24.0),
+      IconTransform::new().scale(2.0),
+    );
+
+    assert_eq!(transform.length(2.0), px(4.0));
+    assert_eq!(transform.apply([0.0, 0.0]), point(px(-12.0), px(-12.0)));
+  }
+
+  #[test]
+  fn test_rotate_accumulates() {
+    let icon = VectorIcon::new(IconName::Heart)
+      .rotate(Radians(1.0))
+      .rotate(Radians(0.5));
+    assert_eq!(icon.transform, IconTransform::new().rotate(Radians(1.5)));
+  }
+
+  #[test]
+  fn test_fit_transform_stretches_and_covers() {
+    let view_box = [0.0, 0.0, 24.0, 24.0];
+    let frame = size(px(48.0), px(24.0));
+    let identity = IconTransform::new();
+    assert_eq!(fit_transform(identity, view_box, frame, None), identity);
+    assert_eq!(
+      fit_transform(identity, view_box, frame, Some(IconFit::Contain)),
+      identity
+    );
+
+    let stretch = fit_transform(identity, view_box, frame, Some(IconFit::Stretch));
+    let transform = ViewBoxTransform::new(view_box, bounds(0.0, 0.0, 48.0, 24.0), stretch);
+    assert_eq!(transform.apply([24.0, 12.0]), point(px(48.0), px(12.0)));
+
+    let cover = fit_transform(identity, view_box, frame, Some(IconFit::Cover));
+    assert_eq!(cover, identity.scale(2.0));
+  }
+}
@@ -0,0 +1,308 @@
+//! Classification of the differences between two icon sets.
+
+use crate::svg::{IconShapes, ParseError, Shape};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Counts for each kind of change.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct Summary {
+  pub added: usize,
+  pub removed: usize,
+  pub renamed: usize,
+  pub modified: usize,
+  pub unchanged: usize,
+}
+
+/// An icon that kept its geometry but changed name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Rename {
+  pub from: String,
+  pub to: String,
+}
+
+/// An icon whose name is unchanged but whose drawing differs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Modification {
+  pub name: String,
+  /// Elements only present in the new version.
+  pub shapes_added: Vec<String>,
+  /// Elements only present in the old version.
+  pub shapes_removed: Vec<String>,
+  /// Root `<svg>` attributes whose value changed.
+  pub root_attributes_changed: Vec<String>,
+  /// The same elements are drawn in a different order.
+  pub shapes_reordered: bool,
+}
+
+/// The full comparison between two icon sets.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+  pub summary: Summary,
+  pub added: Vec<String>,
+  pub removed: Vec<String>,
+  pub renamed: Vec<Rename>,
+  pub modified: Vec<Modification>,
+}
+
+/// Error returned when one of the compared icons cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffError {
+  pub icon: String,
+  pub error: ParseError,
+}
+
+impl fmt::Display for DiffError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}.svg: {}", self.icon, self.error)
+  }
+}
+
+impl std::error::Error for DiffError {}
+
+fn parse_all(icons: &BTreeMap<String, String>) -> Result<BTreeMap<&str, IconShapes>, DiffError> {
+  icons
+    .iter()
+    .map(|(name, markup)| {
+      IconShapes::parse(markup)
+        .map(|shapes| (name.as_str(), shapes))
+        .map_err(|error| DiffError {
+          icon: name.clone(),
+          error,
+        })
+    })
+    .collect()
+}
+
+/// Compares two icon sets keyed by icon name.
+///
+/// Icons missing from `new` whose geometry reappears under a new name are reported as
+/// renames rather than as a removal plus an addition.
+pub fn compare(
+  old: &BTreeMap<String, String>,
+  new: &BTreeMap<String, String>,
+) -> Result<Report, DiffError> {
+  let old = parse_all(old)?;
+  let new = parse_all(new)?;
+  let mut report = Report::default();
+
+  let mut removed: Vec<&str> = Vec::new();
+  for (name, old_icon) in &old {
+    match new.get(name) {
+      Some(new_icon) if new_icon == old_icon => report.summary.unchanged += 1,
+      Some(new_icon) => report.modified.push(modification(name, old_icon, new_icon)),
+      None => removed.push(name),
+    }
+  }
+
+  // Index added icons by geometry so renames can be matched one-to-one.
+  let mut added_by_signature: HashMap<String, Vec<&str>> = HashMap::new();
+  let mut added: Vec<&str> = Vec::new();
+  for (name, new_icon) in &new {
+    if !old.contains_key(name) {
+      added.push(name);
+      added_by_signature
+        .entry(new_icon.signature())
+        .or_default()
+        .push(name);
+    }
+  }
+
+  for name in removed {
+    let renamed_to = added_by_signature
+      .get_mut(&old[name].signature())
+      .filter(|candidates| !candidates.is_empty())
+      .map(|candidates| candidates.remove(0));
+
+    match renamed_to {
+      Some(to) => {
+        added.retain(|added| *added != to);
+        report.renamed.push(Rename {
+          from: name.to_string(),
+          to: to.to_string(),
+        });
+      }
+      None => report.removed.push(name.to_string()),
+    }
+  }
+
+  report.added = added.into_iter().map(str::to_string).collect();
+
+  report.summary.added = report.added.len();
+  report.summary.removed = report.removed.len();
+  report.summary.renamed = report.renamed.len();
+  report.summary.modified = report.modified.len();
+
+  Ok(report)
+}
+
+fn modification(name: &str, old: &IconShapes, new: &IconShapes) -> Modification {
+  let root_attributes_changed = old
+    .root
+    .keys()
+    .chain(new.root.keys())
+    .filter(|key| old.root.get(*key) != new.root.get(*key))
+    .cloned()
+    .collect::<std::collections::BTreeSet<_>>()
+    .into_iter()
+    .collect();
+
+  let shapes_added = shape_difference(&new.shapes, &old.shapes);
+  let shapes_removed = shape_difference(&old.shapes, &new.shapes);
+  let shapes_reordered =
+    shapes_added.is_empty() && shapes_removed.is_empty() && old.shapes != new.shapes;
+
+  Modification {
+    name: name.to_string(),
+    shapes_added,
+    shapes_removed,
+    root_attributes_changed,
+    shapes_reordered,
+  }
+}
+
+/// Returns the elements of `a` that are not matched by an element of `b`, counting
+/// duplicates.
+fn shape_difference(a: &[Shape], b: &[Shape]) -> Vec<String> {
+  let mut remaining: Vec<&Shape> = b.iter().collect();
+  a.iter()
+    .filter(
+      |shape| match remaining.iter().position(|other| other == shape) {
+        Some(index) => {
+          remaining.swap_remove(index);
+          false
+        }
+        None => true,
+      },
+    )
+    .map(ToString::to_string)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn icon(body: &str) -> String {
+    format!(
+      r#"<svg viewBox="0 0 24 24" stroke-width="2">{}</svg>"#,
+      body
+    )
+  }
+
+  fn set(icons: &[(&str, String)]) -> BTreeMap<String, String> {
+    icons
+      .iter()
+      .map(|(name, markup)| (name.to_string(), markup.clone()))
+      .collect()
+  }
+
+  #[test]
+  fn test_identical_sets_are_unchanged() {
+    let icons = set(&[("circle", icon(r#"<circle cx="12" cy="12" r="10" />"#))]);
+    let report = compare(&icons, &icons).unwrap();
+
+    assert_eq!(report.summary.unchanged, 1);
+    assert!(report.added.is_empty());
+    assert!(report.modified.is_empty());
+  }
+
+  #[test]
+  fn test_added_and_removed() {
+    let old = set(&[("minus", icon(r#"<path d="M5 12h14" />"#))]);
+    let new = set(&[(
+      "plus",
+      icon(r#"<path d="M5 12h14" /><path d="M12 5v14" />"#),
+    )]);
+    let report = compare(&old, &new).unwrap();
+
+    assert_eq!(report.added, vec!["plus"]);
+    assert_eq!(report.removed, vec!["minus"]);
+    assert!(report.renamed.is_empty());
+  }
+
+  #[test]
+  fn test_renamed_by_matching_geometry() {
+    let old = set(&[("edit", icon(r#"<path d="M12 20h9" />"#))]);
+    let new = set(&[(
+      "pencil-line",
+      "<svg width=\"24\">\n  <path d=\"M12  20h9\" />\n</svg>".to_string(),
+    )]);
+    let report = compare(&old, &new).unwrap();
+
+    assert_eq!(
+      report.renamed,
+      vec![Rename {
+        from: "edit".into(),
+        to: "pencil-line".into(),
+      }]
+    );
+    assert!(report.added.is_empty());
+    assert!(report.removed.is_empty());
+  }
+
+  #[test]
+  fn test_modified_lists_shape_changes() {
+    let old = set(&[(
+      "x",
+      icon(r#"<path d="M18 6 6 18" /><path d="m6 6 12 12" />"#),
+    )]);
+    let new = set(&[(
+      "x",
+      icon(r#"<path d="M18 6 6 18" /><path d="m6 6 13 13" />"#),
+    )]);
+    let report = compare(&old, &new).unwrap();
+
+    assert_eq!(report.summary.modified, 1);
+    let modification = &report.modified[0];
+    assert_eq!(modification.shapes_added, vec![r#"<path d="m6 6 13 13"/>"#]);
+    assert_eq!(
+      modification.shapes_removed,
+      vec![r#"<path d="m6 6 12 12"/>"#]
+    );
+    assert!(modification.root_attributes_changed.is_empty());
+    assert!(!modification.shapes_reordered);
+  }
+
+  #[test]
+  fn test_reordered_shapes() {
+    let old = set(&[(
+      "x",
+      icon(r#"<path d="M18 6 6 18" /><path d="m6 6 12 12" />"#),
+    )]);
+    let new = set(&[(
+      "x",
+      icon(r#"<path d="m6 6 12 12" /><path d="M18 6 6 18" />"#),
+    )]);
+    let report = compare(&old, &new).unwrap();
+
+    assert_eq!(report.summary.modified, 1);
+    let modification = &report.modified[0];
+    assert!(modification.shapes_reordered);
+    assert!(modification.shapes_added.is_empty());
+    assert!(modification.shapes_removed.is_empty());
+  }
+
+  #[test]
+  fn test_modified_root_attributes() {
+    let old = set(&[("dot", icon(r#"<circle r="1" />"#))]);
+    let new = set(&[(
+      "dot",
+      r#"<svg viewBox="0 0 24 24" stroke-width="1.5"><circle r="1" /></svg>"#.to_string(),
+    )]);
+    let report = compare(&old, &new).unwrap();
+
+    assert_eq!(
+      report.modified[0].root_attributes_changed,
+      vec!["stroke-width"]
+    );
+  }
+
+  #[test]
+  fn test_parse_error_names_icon() {
+    let old = set(&[("broken", "<path d=\"M0 0\" />".to_string())]);
+    let error = compare(&old, &BTreeMap::new()).unwrap_err();
+    assert_eq!(error.icon, "broken");
+  }
+}
@@ -0,0 +1,191 @@
+//! Icon-set diff tool
+//!
+//! Compares two icon sets and classifies each icon as added, removed, renamed or
+//! modified, to review what a Lucide upgrade changes before merging it.
+//!
+//! ```bash
+//! # Compare the working tree against another checkout
+//! cargo run -p icon-diff -- ../lucide/icons icons
+//!
+//! # Compare two git revisions and write a JSON report
+//! cargo run -p icon-diff -- git:main git:HEAD --json report.json
+//! ```
+
+use std::fs;
+use std::process::ExitCode;
+
+mod diff;
+mod source;
+mod svg;
+
+use diff::Report;
+use source::IconSource;
+
+const USAGE: &str = "\
+Usage: icon-diff <OLD> <NEW> [--json <FILE>]
+
+Arguments:
+  <OLD>  Icon set before the upgrade
+  <NEW>  Icon set after the upgrade
+
+An icon set is either a directory of `*.svg` files or `git:<rev>[:<path>]`
+to read `<path>` (default: `icons`) at a revision of the current repository.
+
+Options:
+  --json <FILE>  Write a machine-readable report to FILE (`-` for stdout)
+  -h, --help     Print this help";
+
+struct Args {
+  old: IconSource,
+  new: IconSource,
+  json: Option<String>,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<Args>, String> {
+  let mut sources = Vec::new();
+  let mut json = None;
+  let mut args = args.into_iter();
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "-h" | "--help" => return Ok(None),
+      "--json" => json = Some(args.next().ok_or("`--json` expects a file name")?),
+      flag if flag.starts_with("--") => return Err(format!("unknown option `{}`", flag)),
+      _ => sources.push(IconSource::parse(&arg)),
+    }
+  }
+
+  let [old, new]: [IconSource; 2] = sources
+    .try_into()
+    .map_err(|_| "expected exactly two icon sets".to_string())?;
+
+  Ok(Some(Args { old, new, json }))
+}
+
+fn print_section(title: &str, marker: char, items: &[String]) {
+  if items.is_empty() {
+    return;
+  }
+  println!("\n{} ({}):", title, items.len());
+  for item in items {
+    println!("  {} {}", marker, item);
+  }
+}
+
+fn print_summary(args: &Args, report: &Report) {
+  let summary = &report.summary;
+  println!("Comparing {} -> {}", args.old, args.new);
+  println!(
+    "{} added, {} removed, {} renamed, {} modified, {} unchanged",
+    summary.added, summary.removed, summary.renamed, summary.modified, summary.unchanged
+  );
+
+  print_section("Added", '+', &report.added);
+  print_section("Removed", '-', &report.removed);
+
+  let renamed: Vec<String> = report
+    .renamed
+    .iter()
+    .map(|rename| format!("{} -> {}", rename.from, rename.to))
+    .collect();
+  print_section("Renamed", '>', &renamed);
+
+  let modified: Vec<String> = report
+    .modified
+    .iter()
+    .map(|modification| {
+      let mut details = vec![];
+      if !modification.shapes_added.is_empty() {
+        details.push(format!("+{} shapes", modification.shapes_added.len()));
+      }
+      if !modification.shapes_removed.is_empty() {
+        details.push(format!("-{} shapes", modification.shapes_removed.len()));
+      }
+      if modification.shapes_reordered {
+        details.push("shapes reordered".to_string());
+      }
+      if !modification.root_attributes_changed.is_empty() {
+        details.push(modification.root_attributes_changed.join(", "));
+      }
+      format!("{} ({})", modification.name, details.join("; "))
+    })
+    .collect();
+  print_section("Modified", '~', &modified);
+}
+
+fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+  let old = args
+    .old
+    .load()
+    .map_err(|e| format!("{}: {}", args.old, e))?;
+  let new = args
+    .new
+    .load()
+    .map_err(|e| format!("{}: {}", args.new, e))?;
+  let report = diff::compare(&old, &new)?;
+
+  match args.json.as_deref() {
+    Some("-") => println!("{}", serde_json::to_string_pretty(&report)?),
+    Some(path) => {
+      print_summary(&args, &report);
+      fs::write(path, serde_json::to_string_pretty(&report)?)?;
+      println!("\nJSON report written to {}", path);
+    }
+    None => print_summary(&args, &report),
+  }
+
+  Ok(())
+}
+
+fn main() -> ExitCode {
+  let args = match parse_args(std::env::args().skip(1)) {
+    Ok(Some(args)) => args,
+    Ok(None) => {
+      println!("{}", USAGE);
+      return ExitCode::SUCCESS;
+    }
+    Err(error) => {
+      eprintln!("error: {}\n\n{}", error, USAGE);
+      return ExitCode::from(2);
+    }
+  };
+
+  match run(args) {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(error) => {
+      eprintln!("error: {}", error);
+      ExitCode::FAILURE
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn args(values: &[&str]) -> Result<Option<Args>, String> {
+    parse_args(values.iter().map(|value| value.to_string()))
+  }
+
+  #[test]
+  fn test_parse_args_reads_sources_and_json() {
+    let parsed = args(&["old", "git:HEAD", "--json", "report.json"])
+      .unwrap()
+      .unwrap();
+
+    assert_eq!(parsed.old, IconSource::parse("old"));
+    assert_eq!(parsed.new, IconSource::parse("git:HEAD"));
+    assert_eq!(parsed.json.as_deref(), Some("report.json"));
+  }
+
+  #[test]
+  fn test_parse_args_requires_two_sources() {
+    assert!(args(&["only-one"]).is_err());
+    assert!(args(&["a", "b", "c"]).is_err());
+  }
+
+  #[test]
+  fn test_parse_args_help() {
+    assert!(args(&["--help"]).unwrap().is_none());
+  }
+}
@@ -0,0 +1,255 @@
+//! Loading icon sets from a directory or from a git revision.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Directory used when a git source does not name one.
+const DEFAULT_GIT_PATH: &str = "icons";
+
+/// Where to read an icon set from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IconSource {
+  /// A directory of `*.svg` files on disk.
+  Directory(PathBuf),
+  /// A directory of `*.svg` files at a git revision of the current repository.
+  Git { rev: String, path: String },
+}
+
+impl IconSource {
+  /// Parses a command line argument.
+  ///
+  /// `git:<rev>` and `git:<rev>:<path>` read from the repository, anything else is a
+  /// directory.
+  pub fn parse(arg: &str) -> Self {
+    match arg.strip_prefix("git:") {
+      Some(spec) => {
+        let (rev, path) = spec.split_once(':').unwrap_or((spec, DEFAULT_GIT_PATH));
+        Self::Git {
+          rev: rev.to_string(),
+          path: path.trim_end_matches('/').to_string(),
+        }
+      }
+      None => Self::Directory(PathBuf::from(arg)),
+    }
+  }
+
+  /// Reads every icon, keyed by file stem (the kebab-case icon name).
+  pub fn load(&self) -> io::Result<BTreeMap<String, String>> {
+    match self {
+      Self::Directory(dir) => load_directory(dir),
+      Self::Git { rev, path } => load_git(rev, path),
+    }
+  }
+}
+
+impl std::fmt::Display for IconSource {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Directory(dir) => write!(f, "{}", dir.display()),
+      Self::Git { rev, path } => write!(f, "{}:{}", rev, path),
+    }
+  }
+}
+
+fn icon_name(file_name: &str) -> Option<&str> {
+  file_name.strip_suffix(".svg")
+}
+
+fn load_directory(dir: &Path) -> io::Result<BTreeMap<String, String>> {
+  let mut icons = BTreeMap::new();
+
+  for entry in fs::read_dir(dir)? {
+    let entry = entry?;
+    let file_name = entry.file_name();
+    let Some(name) = file_name.to_str().and_then(icon_name) else {
+      continue;
+    };
+    icons.insert(name.to_string(), fs::read_to_string(entry.path())?);
+  }
+
+  Ok(icons)
+}
+
+fn load_git(rev: &str, path: &str) -> io::Result<BTreeMap<String, String>> {
+  let listing = git(&["ls-tree", rev, &format!("{}/", path)])?;
+
+  // Each line is `<mode> <type> <object>\t<path>`.
+  let mut blobs = Vec::new();
+  for line in listing.lines() {
+    let Some((meta, file_path)) = line.split_once('\t') else {
+      continue;
+    };
+    let mut meta = meta.split_whitespace();
+    let (Some("blob"), Some(object)) = (meta.nth(1), meta.next()) else {
+      continue;
+    };
+    let file_name = file_path.rsplit('/').next().unwrap_or(file_path);
+    if let Some(name) = icon_name(file_name) {
+      blobs.push((name.to_string(), object.to_string()));
+    }
+  }
+
+  if blobs.is_empty() {
+    return Err(io::Error::new(
+      io::ErrorKind::NotFound,
+      format!("no svg files under `{}` at `{}`", path, rev),
+    ));
+  }
+
+  // Stream every blob through a single `cat-file` process instead of spawning one
+  // `git show` per icon.
+  let mut child = Command::new("git")
+    .args(["cat-file", "--batch"])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()?;
+
+  let mut stdin = child.stdin.take().expect("stdin is piped");
+  let objects: Vec<String> = blobs.iter().map(|(_, object)| object.clone()).collect();
+  let writer = std::thread::spawn(move || -> io::Result<()> {
+    for object in objects {
+      writeln!(stdin, "{}", object)?;
+    }
+    Ok(())
+  });
+
+  let mut stderr = child.stderr.take().expect("stderr is piped");
+  let errors = std::thread::spawn(move || {
+    let mut errors = String::new();
+    stderr.read_to_string(&mut errors).map(|_| errors)
+  });
+
+  let mut stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+  let icons = read_blobs(&mut stdout, blobs);
+  drop(stdout);
+
+  let written = writer.join().expect("git writer thread panicked");
+  let status = child.wait()?;
+  let errors = errors.join().expect("git stderr thread panicked")?;
+  // A failed git call ends its output early, so report it rather than the read error.
+  if !status.success() {
+    return Err(io::Error::other(format!(
+      "git cat-file --batch failed ({}): {}",
+      status,
+      errors.trim()
+    )));
+  }
+  written?;
+  icons
+}
+
+/// Reads one `cat-file --batch` record per blob, in order.
+fn read_blobs(
+  stdout: &mut impl BufRead,
+  blobs: Vec<(String, String)>,
+) -> io::Result<BTreeMap<String, String>> {
+  let mut icons = BTreeMap::new();
+  for (name, _) in blobs {
+    let mut header = String::new();
+    stdout.read_line(&mut header)?;
+    let size: usize = header
+      .split_whitespace()
+      .nth(2)
+      .and_then(|size| size.parse().ok())
+      .ok_or_else(|| io::Error::other(format!("unexpected git output: {}", header.trim())))?;
+
+    let mut content = vec![0; size + 1];
+    stdout.read_exact(&mut content)?;
+    content.truncate(size);
+
+    let markup = String::from_utf8(content).map_err(|e| io::Error::other(e.to_string()))?;
+    icons.insert(name, markup);
+  }
+  Ok(icons)
+}
+
+fn git(args: &[&str]) -> io::Result<String> {
+  let output = Command::new("git").args(args).output()?;
+  if !output.status.success() {
+    return Err(io::Error::other(format!(
+      "git {} failed: {}",
+      args.join(" "),
+      String::from_utf8_lossy(&output.stderr).trim()
+    )));
+  }
+  String::from_utf8(output.stdout).map_err(|e| io::Error::other(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_directory() {
+    assert_eq!(
+      IconSource::parse("../lucide/icons"),
+      IconSource::Directory(PathBuf::from("../lucide/icons"))
+    );
+  }
+
+  #[test]
+  fn test_parse_git_with_default_path() {
+    assert_eq!(
+      IconSource::parse("git:HEAD~1"),
+      IconSource::Git {
+        rev: "HEAD~1".into(),
+        path: "icons".into(),
+      }
+    );
+  }
+
+  #[test]
+  fn test_parse_git_with_path() {
+    assert_eq!(
+      IconSource::parse("git:v0.400.0:assets/icons/"),
+      IconSource::Git {
+        rev: "v0.400.0".into(),
+        path: "assets/icons".into(),
+      }
+    );
+  }
+
+  #[test]
+  fn test_load_directory_reads_repository_icons() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../icons");
+    let icons = IconSource::Directory(dir).load().unwrap();
+
+    assert!(icons.contains_key("heart"));
+    assert!(icons.values().all(|markup| markup.contains("<svg")));
+  }
+
+  #[test]
+  fn test_read_blobs() {
+    let mut output = io::Cursor::new("abc blob 5\n<svg>\ndef blob 3\nsvg\n");
+    let icons = read_blobs(
+      &mut output,
+      vec![("a".into(), "abc".into()), ("b".into(), "def".into())],
+    )
+    .unwrap();
+    assert_eq!(icons["a"], "<svg>");
+    assert_eq!(icons["b"], "svg");
+  }
+
+  #[test]
+  fn test_read_blobs_rejects_missing_and_truncated_output() {
+    let mut missing = io::Cursor::new("abc missing\n");
+    assert!(read_blobs(&mut missing, vec![("a".into(), "abc".into())]).is_err());
+
+    let mut truncated = io::Cursor::new("abc blob 5\n<svg>\n");
+    let blobs = vec![("a".into(), "abc".into()), ("b".into(), "def".into())];
+    assert!(read_blobs(&mut truncated, blobs).is_err());
+  }
+
+  #[test]
+  fn test_load_git_reports_bad_revisions() {
+    let source = IconSource::Git {
+      rev: "no-such-revision-for-icon-diff".into(),
+      path: "icons".into(),
+    };
+    assert!(source.load().is_err());
+  }
+}
@@ -0,0 +1,226 @@
+//! Minimal SVG reader used to compare icons by the shapes they draw.
+//!
+//! Lucide icons are flat documents made of a root `<svg>` element and a handful of
+//! `path`, `circle`, `rect`, `line`, `polyline` and `polygon` children, so a small
+//! tag scanner is enough and keeps the tool free of an XML dependency.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A single drawing element with its attributes.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Shape {
+  pub tag: String,
+  pub attributes: BTreeMap<String, String>,
+}
+
+impl fmt::Display for Shape {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "<{}", self.tag)?;
+    for (name, value) in &self.attributes {
+      write!(f, " {}=\"{}\"", name, value)?;
+    }
+    write!(f, "/>")
+  }
+}
+
+/// The parsed content of an icon file.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IconShapes {
+  /// Attributes of the root `<svg>` element.
+  pub root: BTreeMap<String, String>,
+  /// Drawing elements in document order.
+  pub shapes: Vec<Shape>,
+}
+
+/// Error returned when an icon file is not well-formed enough to compare.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError(String);
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+impl IconShapes {
+  /// Parses SVG markup into its root attributes and drawing elements.
+  pub fn parse(markup: &str) -> Result<Self, ParseError> {
+    let mut icon = IconShapes::default();
+    let mut has_root = false;
+    let mut rest = markup;
+
+    while let Some(start) = rest.find('<') {
+      rest = &rest[start + 1..];
+
+      if let Some(comment) = rest.strip_prefix("!--") {
+        let end = comment
+          .find("-->")
+          .ok_or_else(|| ParseError("unterminated comment".into()))?;
+        rest = &comment[end + 3..];
+        continue;
+      }
+
+      let end = find_tag_end(rest).ok_or_else(|| ParseError("unterminated tag".into()))?;
+      let tag = &rest[..end];
+      rest = &rest[end + 1..];
+
+      if tag.starts_with('?') || tag.starts_with('!') || tag.starts_with('/') {
+        continue;
+      }
+
+      let shape = parse_tag(tag.trim_end_matches('/'))?;
+      if shape.tag == "svg" && !has_root {
+        has_root = true;
+        icon.root = shape.attributes;
+      } else {
+        icon.shapes.push(shape);
+      }
+    }
+
+    if !has_root {
+      return Err(ParseError("missing <svg> root element".into()));
+    }
+
+    Ok(icon)
+  }
+
+  /// Returns a key identifying the drawn geometry.
+  ///
+  /// The key ignores formatting, attribute order and the root element, so two files
+  /// drawing the same shapes share a signature even when they are named differently.
+  pub fn signature(&self) -> String {
+    self
+      .shapes
+      .iter()
+      .map(ToString::to_string)
+      .collect::<Vec<_>>()
+      .join("")
+  }
+}
+
+/// Finds the closing `>` of a tag, skipping over quoted attribute values.
+fn find_tag_end(tag: &str) -> Option<usize> {
+  let mut quote = None;
+  for (index, c) in tag.char_indices() {
+    match (quote, c) {
+      (None, '"' | '\'') => quote = Some(c),
+      (Some(q), c) if c == q => quote = None,
+      (None, '>') => return Some(index),
+      _ => {}
+    }
+  }
+  None
+}
+
+fn parse_tag(tag: &str) -> Result<Shape, ParseError> {
+  let tag = tag.trim();
+  let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+  let name = &tag[..name_end];
+  if name.is_empty() {
+    return Err(ParseError("empty tag name".into()));
+  }
+
+  let mut attributes = BTreeMap::new();
+  let mut rest = tag[name_end..].trim_start();
+
+  while !rest.is_empty() {
+    let eq = rest
+      .find('=')
+      .ok_or_else(|| ParseError(format!("attribute without value in <{}>", name)))?;
+    let attribute = rest[..eq].trim().to_string();
+    let value_part = rest[eq + 1..].trim_start();
+
+    let quote = value_part
+      .chars()
+      .next()
+      .filter(|c| *c == '"' || *c == '\'')
+      .ok_or_else(|| ParseError(format!("unquoted value for `{}` in <{}>", attribute, name)))?;
+    let value_end = value_part[1..].find(quote).ok_or_else(|| {
+      ParseError(format!(
+        "unterminated value for `{}` in <{}>",
+        attribute, name
+      ))
+    })?;
+
+    let value = value_part[1..=value_end]
+      .split_whitespace()
+      .collect::<Vec<_>>()
+      .join(" ");
+    attributes.insert(attribute, value);
+    rest = value_part[value_end + 2..].trim_start();
+  }
+
+  Ok(Shape {
+    tag: name.to_string(),
+    attributes,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ARROW_DOWN: &str = r#"<svg
+  xmlns="http://www.w3.org/2000/svg"
+  width="24"
+  height="24"
+  viewBox="0 0 24 24"
+  stroke-width="2"
+>
+  <path d="M12 5v14" />
+  <path d="m19 12-7 7-7-7" />
+</svg>"#;
+
+  #[test]
+  fn test_parse_reads_root_and_shapes() {
+    let icon = IconShapes::parse(ARROW_DOWN).unwrap();
+
+    assert_eq!(
+      icon.root.get("viewBox").map(String::as_str),
+      Some("0 0 24 24")
+    );
+    assert_eq!(icon.shapes.len(), 2);
+    assert_eq!(icon.shapes[0].tag, "path");
+    assert_eq!(
+      icon.shapes[1].attributes.get("d").map(String::as_str),
+      Some("m19 12-7 7-7-7")
+    );
+  }
+
+  #[test]
+  fn test_signature_ignores_formatting_and_attribute_order() {
+    let compact = r#"<svg viewBox="0 0 24 24"><circle r="10" cx="12"   cy="12"/></svg>"#;
+    let spread = r#"<svg width="24">
+      <!-- a comment -->
+      <circle cx="12" cy="12" r="10" />
+    </svg>"#;
+
+    let compact = IconShapes::parse(compact).unwrap();
+    let spread = IconShapes::parse(spread).unwrap();
+
+    assert_eq!(compact.signature(), spread.signature());
+    assert_ne!(compact.root, spread.root);
+  }
+
+  #[test]
+  fn test_parse_allows_angle_brackets_in_values() {
+    let icon = IconShapes::parse(r#"<svg><text label="a > b" /></svg>"#).unwrap();
+    assert_eq!(
+      icon.shapes[0].attributes.get("label").map(String::as_str),
+      Some("a > b")
+    );
+  }
+
+  #[test]
+  fn test_parse_rejects_missing_root() {
+    assert!(IconShapes::parse(r#"<path d="M0 0" />"#).is_err());
+  }
+
+  #[test]
+  fn test_parse_rejects_unterminated_tag() {
+    assert!(IconShapes::parse(r#"<svg><path d="M0 0" </svg"#).is_err());
+  }
+}
@@ -0,0 +1,379 @@
+//! Build script to generate IconName enum, paths and metadata from the icon files
+
+use heck::ToUpperCamelCase;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/path_data.rs"]
+mod path_data;
+
+use path_data::{PathCommand, VectorData};
+
+fn point_literal(point: [f32; 2]) -> String {
+  format!("[{:?}, {:?}]", point[0], point[1])
+}
+
+fn command_literal(command: &PathCommand) -> String {
+  match command {
+    PathCommand::MoveTo(to) => format!("PathCommand::MoveTo({})", point_literal(*to)),
+    PathCommand::LineTo(to) => format!("PathCommand::LineTo({})", point_literal(*to)),
+    PathCommand::CubicTo { ctrl1, ctrl2, to } => format!(
+      "PathCommand::CubicTo {{ ctrl1: {}, ctrl2: {}, to: {} }}",
+      point_literal(*ctrl1),
+      point_literal(*ctrl2),
+      point_literal(*to)
+    ),
+    PathCommand::Close => "PathCommand::Close".to_string(),
+  }
+}
+
+fn vector_literal(data: &VectorData) -> String {
+  let elements: Vec<String> = data
+    .elements
+    .iter()
+    .map(|element| {
+      let commands: Vec<String> = element.commands.iter().map(command_literal).collect();
+      format!(
+        "VectorElement {{ filled: {}, commands: Cow::Borrowed(&[{}]) }}",
+        element.filled,
+        commands.join(", ")
+      )
+    })
+    .collect();
+
+  format!(
+    "VectorData {{ view_box: [{:?}, {:?}, {:?}, {:?}], stroke_width: {:?}, elements: Cow::Borrowed(&[{}]) }}",
+    data.view_box[0],
+    data.view_box[1],
+    data.view_box[2],
+    data.view_box[3],
+    data.stroke_width,
+    elements.join(", ")
+  )
+}
+
+/// Tags, categories and former names from an icon's `<name>.json` metadata file.
+#[derive(Default)]
+struct IconMetadata {
+  tags: Vec<String>,
+  categories: Vec<String>,
+  aliases: Vec<String>,
+}
+
+fn read_metadata(path: &Path) -> IconMetadata {
+  let Ok(content) = fs::read_to_string(path) else {
+    return IconMetadata::default();
+  };
+  let json: serde_json::Value = serde_json::from_str(&content)
+    .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path.display(), e));
+
+  let strings = |key: &str| -> Vec<String> {
+    json[key]
+      .as_array()
+      .map(|values| {
+        values
+          .iter()
+          .filter_map(|value| value.as_str().map(str::to_string))
+          .collect()
+      })
+      .unwrap_or_default()
+  };
+
+  // Aliases are either plain names or objects with a `name` field.
+  let aliases = json["aliases"]
+    .as_array()
+    .map(|values| {
+      values
+        .iter()
+        .filter_map(|value| value.as_str().or_else(|| value["name"].as_str()))
+        .map(str::to_string)
+        .collect()
+    })
+    .unwrap_or_default();
+
+  IconMetadata {
+    tags: strings("tags"),
+    categories: strings("categories"),
+    aliases,
+  }
+}
+
+fn str_slice_literal(values: &[String]) -> String {
+  let values: Vec<String> = values.iter().map(|value| format!("{:?}", value)).collect();
+  format!("&[{}]", values.join(", "))
+}
+
+fn main() {
+  let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+  let icons_dir = Path::new(&manifest_dir)
+    .parent()
+    .unwrap()
+    .parent()
+    .unwrap()
+    .join("icons");
+  let out_dir = env::var("OUT_DIR").unwrap();
+  let dest_path = Path::new(&out_dir).join("icons_generated.rs");
+
+  println!("cargo:rerun-if-changed={}", icons_dir.display());
+  println!("cargo:rerun-if-changed=src/path_data.rs");
+
+  let mut icon_entries: Vec<(String, String, String)> = Vec::new();
+  let mut metadata: Vec<IconMetadata> = Vec::new();
+
+  if icons_dir.exists() {
+    let mut entries: Vec<_> = fs::read_dir(&icons_dir)
+      .expect("Failed to read icons directory")
+      .filter_map(|e| e.ok())
+      .filter(|e| {
+        e.path()
+          .extension()
+          .map(|ext| ext == "svg")
+          .unwrap_or(false)
+      })
+      .collect();
+
+    entries.sort_by_key(|e| e.path());
+
+    for entry in entries {
+      let path = entry.path();
+      let file_stem = path.file_stem().unwrap().to_str().unwrap();
+
+      let variant_name = file_stem.to_upper_camel_case();
+
+      // Handle names starting with numbers
+      let variant_name = if variant_name
+        .chars()
+        .next()
+        .map(|c| c.is_numeric())
+        .unwrap_or(false)
+      {
+        format!("N{}", variant_name)
+      } else {
+        variant_name
+      };
+
+      let file_name = format!("{}.svg", file_stem);
+
+      metadata.push(read_metadata(
+        &icons_dir.join(format!("{}.json", file_stem)),
+      ));
+      icon_entries.push((variant_name, file_stem.to_string(), file_name));
+    }
+  }
+
+  let missing = icon_entries
+    .iter()
+    .filter(|(_, file_stem, _)| !icons_dir.join(format!("{}.json", file_stem)).exists())
+    .count();
+  if missing > 0 {
+    panic!(
+      "{} of {} icons have no `.json` metadata in {}; run scripts/download-icons.sh \
+       to fetch the tags, categories and aliases",
+      missing,
+      icon_entries.len(),
+      icons_dir.display()
+    );
+  }
+
+  let mut code = String::new();
+
+  // Generate enum variants
+  code.push_str("/// All available Lucide icon names.\n");
+  code.push_str("///\n");
+  code.push_str("/// This enum is auto-generated from the SVG files in the `icons/` directory.\n");
+  code.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+  code.push_str("pub enum IconName {\n");
+
+  for (variant_name, file_stem, _) in &icon_entries {
+    code.push_str(&format!("    /// {}\n", file_stem));
+    code.push_str(&format!("    {},\n", variant_name));
+  }
+
+  code.push_str("}\n\n");
+
+  // Generate path() implementation
+  code.push_str("impl IconName {\n");
+  code.push_str("    /// Returns the asset path for this icon.\n");
+  code.push_str("    pub fn path(&self) -> &'static str {\n");
+  code.push_str("        match self {\n");
+
+  for (variant_name, _, file_name) in &icon_entries {
+    code.push_str(&format!(
+      "            IconName::{} => \"icons/{}\",\n",
+      variant_name, file_name
+    ));
+  }
+
+  code.push_str("        }\n");
+  code.push_str("    }\n\n");
+
+  // Generate name() for display
+  code.push_str("    /// Returns the display name (kebab-case) for this icon.\n");
+  code.push_str("    pub fn name(&self) -> &'static str {\n");
+  code.push_str("        match self {\n");
+
+  for (variant_name, file_stem, _) in &icon_entries {
+    code.push_str(&format!(
+      "            IconName::{} => \"{}\",\n",
+      variant_name, file_stem
+    ));
+  }
+
+  code.push_str("        }\n");
+  code.push_str("    }\n\n");
+
+  // Generate svg() with the markup embedded at compile time
+  code.push_str("    /// Returns the SVG markup for this icon.\n");
+  code.push_str("    pub fn svg(&self) -> &'static str {\n");
+  code.push_str("        match self {\n");
+
+  for (variant_name, _, file_name) in &icon_entries {
+    code.push_str(&format!(
+      "            IconName::{} => include_str!({:?}),\n",
+      variant_name,
+      icons_dir.join(file_name).display().to_string()
+    ));
+  }
+
+  code.push_str("        }\n");
+  code.push_str("    }\n\n");
+
+  // Generate vector() backed by geometry parsed at build time
+  code.push_str("    /// Returns the icon geometry as drawing commands.\n");
+  code.push_str("    pub fn vector(&self) -> &'static crate::VectorData {\n");
+  code.push_str("        &VECTORS[*self as usize]\n");
+  code.push_str("    }\n\n");
+
+  // Generate from_path() as the inverse of path()
+  code.push_str("    /// Returns the icon whose asset path is `path`, if any.\n");
+  code.push_str("    pub fn from_path(path: &str) -> Option<IconName> {\n");
+  code.push_str("        match path {\n");
+
+  for (variant_name, _, file_name) in &icon_entries {
+    code.push_str(&format!(
+      "            \"icons/{}\" => Some(IconName::{}),\n",
+      file_name, variant_name
+    ));
+  }
+
+  code.push_str("            _ => None,\n");
+  code.push_str("        }\n");
+  code.push_str("    }\n\n");
+
+  // Generate tags(), categories() and aliases() from the metadata files
+  code.push_str("    /// Returns the search tags for this icon.\n");
+  code.push_str("    pub fn tags(&self) -> &'static [&'static str] {\n");
+  code.push_str("        match self {\n");
+
+  for ((variant_name, _, _), meta) in icon_entries.iter().zip(&metadata) {
+    code.push_str(&format!(
+      "            IconName::{} => {},\n",
+      variant_name,
+      str_slice_literal(&meta.tags)
+    ));
+  }
+
+  code.push_str("        }\n");
+  code.push_str("    }\n\n");
+
+  code.push_str("    /// Returns the categories this icon belongs to.\n");
+  code.push_str("    pub fn categories(&self) -> &'static [&'static str] {\n");
+  code.push_str("        match self {\n");
+
+  for ((variant_name, _, _), meta) in icon_entries.iter().zip(&metadata) {
+    code.push_str(&format!(
+      "            IconName::{} => {},\n",
+      variant_name,
+      str_slice_literal(&meta.categories)
+    ));
+  }
+
+  code.push_str("        }\n");
+  code.push_str("    }\n\n");
+
+  code.push_str("    /// Returns former names of this icon, kept by Lucide for compatibility.\n");
+  code.push_str("    pub fn aliases(&self) -> &'static [&'static str] {\n");
+  code.push_str("        match self {\n");
+
+  for ((variant_name, _, _), meta) in icon_entries.iter().zip(&metadata) {
+    code.push_str(&format!(
+      "            IconName::{} => {},\n",
+      variant_name,
+      str_slice_literal(&meta.aliases)
+    ));
+  }
+
+  code.push_str("        }\n");
+  code.push_str("    }\n\n");
+
+  // Generate from_name() as the inverse of name()
+  code.push_str("    /// Returns the icon with the given kebab-case name, if any.\n");
+  code.push_str("    pub fn from_name(name: &str) -> Option<IconName> {\n");
+  code.push_str("        match name {\n");
+
+  for (variant_name, file_stem, _) in &icon_entries {
+    code.push_str(&format!(
+      "            \"{}\" => Some(IconName::{}),\n",
+      file_stem, variant_name
+    ));
+  }
+
+  code.push_str("            _ => None,\n");
+  code.push_str("        }\n");
+  code.push_str("    }\n\n");
+
+  // Generate all() iterator
+  code.push_str("    /// Returns an iterator over all icon names.\n");
+  code.push_str("    pub fn all() -> impl Iterator<Item = IconName> {\n");
+  code.push_str("        [\n");
+
+  for (variant_name, _, _) in &icon_entries {
+    code.push_str(&format!("            IconName::{},\n", variant_name));
+  }
+
+  code.push_str("        ].into_iter()\n");
+  code.push_str("    }\n\n");
+
+  // Generate count
+  code.push_str(&format!(
+    "    /// Returns the total number of available icons ({}).\n",
+    icon_entries.len()
+  ));
+  code.push_str("    pub const fn count() -> usize {\n");
+  code.push_str(&format!("        {}\n", icon_entries.len()));
+  code.push_str("    }\n");
+
+  code.push_str("}\n\n");
+
+  // Generate the geometry table, indexed by variant. Coordinates such as 6.28 would
+  // otherwise trip clippy's approx_constant lint.
+  code.push_str("#[allow(clippy::approx_constant)]\n");
+  code.push_str(&format!(
+    "static VECTORS: [crate::VectorData; {}] = {{\n",
+    icon_entries.len()
+  ));
+  code.push_str("    use crate::{PathCommand, VectorData, VectorElement};\n");
+  code.push_str("    use std::borrow::Cow;\n");
+  code.push_str("    [\n");
+
+  for (_, _, file_name) in &icon_entries {
+    let markup = fs::read_to_string(icons_dir.join(file_name)).expect("Failed to read icon");
+    let data =
+      VectorData::parse(&markup).unwrap_or_else(|e| panic!("Failed to parse {}: {}", file_name, e));
+    code.push_str(&format!("        {},\n", vector_literal(&data)));
+  }
+
+  code.push_str("    ]\n");
+  code.push_str("};\n\n");
+
+  // Implement Display
+  code.push_str("impl std::fmt::Display for IconName {\n");
+  code.push_str("    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n");
+  code.push_str("        write!(f, \"{}\", self.name())\n");
+  code.push_str("    }\n");
+  code.push_str("}\n");
+
+  fs::write(&dest_path, code).expect("Failed to write generated code");
+}
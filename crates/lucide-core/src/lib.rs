@@ -0,0 +1,178 @@
+//! # lucide-core
+//!
+//! [Lucide](https://lucide.dev) icon data without UI dependencies.
+//!
+//! This crate holds the generated `IconName` enum with each icon's name, asset path,
+//! SVG markup, drawing geometry, tags, categories and aliases. It has no runtime
+//! dependencies, so services and command line tools can validate and list icon names
+//! without pulling in a UI framework. `gpui-lucide` re-exports everything here.
+//!
+//! ```rust
+//! use lucide_core::IconName;
+//!
+//! let icon: IconName = "heart".parse().unwrap();
+//! assert_eq!(icon, IconName::Heart);
+//! assert_eq!(icon.path(), "icons/heart.svg");
+//! assert!(icon.svg().contains("<svg"));
+//! ```
+
+mod path_data;
+
+pub use path_data::*;
+
+use std::fmt;
+use std::str::FromStr;
+
+// Include the generated icon names
+include!(concat!(env!("OUT_DIR"), "/icons_generated.rs"));
+
+/// Error returned when parsing a string that is not a known icon name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIconNameError {
+  name: String,
+}
+
+impl ParseIconNameError {
+  /// Returns the name that failed to parse.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+}
+
+impl fmt::Display for ParseIconNameError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "unknown icon name: `{}`", self.name)
+  }
+}
+
+impl std::error::Error for ParseIconNameError {}
+
+impl FromStr for IconName {
+  type Err = ParseIconNameError;
+
+  /// Parses a kebab-case icon name such as `arrow-right`.
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    IconName::from_name(name).ok_or_else(|| ParseIconNameError {
+      name: name.to_string(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashSet;
+  use std::ffi::OsStr;
+  use std::fs;
+  use std::path::Path;
+
+  #[test]
+  fn test_icon_name_path() {
+    let path = IconName::Heart.path();
+    assert!(path.starts_with("icons/"));
+    assert!(path.ends_with(".svg"));
+  }
+
+  #[test]
+  fn test_icon_name_display() {
+    let name = IconName::Heart;
+    assert_eq!(name.to_string(), "heart");
+  }
+
+  #[test]
+  fn test_icon_count() {
+    assert!(IconName::count() > 1000);
+  }
+
+  #[test]
+  fn test_all_icons_iterator() {
+    let count = IconName::all().count();
+    assert_eq!(count, IconName::count());
+  }
+
+  #[test]
+  fn test_icon_count_matches_icons_directory() {
+    let icons_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../icons");
+    let svg_count = fs::read_dir(&icons_dir)
+      .expect("icons directory should be readable")
+      .filter_map(Result::ok)
+      .filter(|entry| entry.path().extension() == Some(OsStr::new("svg")))
+      .count();
+
+    assert_eq!(IconName::count(), svg_count);
+  }
+
+  #[test]
+  fn test_all_icon_name_path_mappings() {
+    for icon in IconName::all() {
+      let expected = format!("icons/{}.svg", icon.name());
+      assert_eq!(icon.path(), expected.as_str());
+    }
+  }
+
+  #[test]
+  fn test_every_icon_has_markup() {
+    for icon in IconName::all() {
+      assert!(icon.svg().contains("<svg"), "missing markup: {}", icon);
+    }
+  }
+
+  #[test]
+  fn test_every_icon_has_vector_geometry() {
+    for icon in IconName::all() {
+      let vector = icon.vector();
+      assert_eq!(vector.view_box, [0.0, 0.0, 24.0, 24.0], "{}", icon);
+      assert!(!vector.elements.is_empty(), "no geometry for {}", icon);
+    }
+  }
+
+  #[test]
+  fn test_names_and_paths_are_unique() {
+    let mut names = HashSet::new();
+    let mut paths = HashSet::new();
+
+    for icon in IconName::all() {
+      assert!(
+        names.insert(icon.name()),
+        "duplicate icon name: {}",
+        icon.name()
+      );
+      assert!(
+        paths.insert(icon.path()),
+        "duplicate icon path: {}",
+        icon.path()
+      );
+    }
+  }
+
+  #[test]
+  fn test_from_str_round_trips_every_name() {
+    for icon in IconName::all() {
+      assert_eq!(icon.name().parse::<IconName>(), Ok(icon));
+      assert_eq!(IconName::from_path(icon.path()), Some(icon));
+    }
+  }
+
+  #[test]
+  fn test_from_str_rejects_unknown_name() {
+    let error = "not-an-icon".parse::<IconName>().unwrap_err();
+    assert_eq!(error.name(), "not-an-icon");
+    assert_eq!(error.to_string(), "unknown icon name: `not-an-icon`");
+    assert!("Heart".parse::<IconName>().is_err());
+  }
+
+  #[test]
+  fn test_metadata_is_shipped() {
+    assert!(IconName::Trash2.tags().contains(&"garbage"));
+    assert!(IconName::ArrowDown.categories().contains(&"arrows"));
+
+    let tagged = IconName::all()
+      .filter(|icon| !icon.tags().is_empty())
+      .count();
+    assert!(
+      tagged * 10 >= IconName::all().count() * 9,
+      "only {} icons have tags; run scripts/download-icons.sh",
+      tagged
+    );
+  }
+}
@@ -0,0 +1,1020 @@
+//! Icon geometry parsed into drawing commands.
+//!
+//! SVG primitives (`path`, `circle`, `ellipse`, `rect`, `line`, `polyline` and
+//! `polygon`) are normalized into absolute move, line and cubic commands so they can be
+//! stroked directly. This module has no dependencies: the build script includes it to
+//! precompute the geometry of the bundled icons.
+
+use std::borrow::Cow;
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::fmt;
+
+/// A point in viewBox units.
+pub type VectorPoint = [f32; 2];
+
+/// A drawing command with absolute coordinates in viewBox units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathCommand {
+  /// Starts a new subpath.
+  MoveTo(VectorPoint),
+  /// Draws a straight line.
+  LineTo(VectorPoint),
+  /// Draws a cubic Bézier curve through two control points.
+  CubicTo {
+    ctrl1: VectorPoint,
+    ctrl2: VectorPoint,
+    to: VectorPoint,
+  },
+  /// Closes the current subpath.
+  Close,
+}
+
+/// A single SVG primitive converted into commands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorElement {
+  /// Whether the element is filled in addition to being stroked.
+  pub filled: bool,
+  pub commands: Cow<'static, [PathCommand]>,
+}
+
+/// The geometry of a whole icon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorData {
+  /// `min-x`, `min-y`, `width` and `height` of the viewBox.
+  pub view_box: [f32; 4],
+  /// Stroke width in viewBox units.
+  pub stroke_width: f32,
+  /// Elements in document order.
+  pub elements: Cow<'static, [VectorElement]>,
+}
+
+/// Error returned when SVG markup cannot be converted into commands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathDataError(String);
+
+impl fmt::Display for PathDataError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+impl std::error::Error for PathDataError {}
+
+const DEFAULT_VIEW_BOX: [f32; 4] = [0.0, 0.0, 24.0, 24.0];
+const DEFAULT_STROKE_WIDTH: f32 = 2.0;
+
+/// Control point distance that approximates a quarter circle with a cubic curve.
+const KAPPA: f32 = 0.552_284_8;
+
+impl VectorData {
+  /// Parses SVG markup.
+  ///
+  /// Unknown elements such as `<g>` or `<title>` are skipped, the viewBox and stroke
+  /// width default to Lucide's `0 0 24 24` and `2`.
+  pub fn parse(markup: &str) -> Result<Self, PathDataError> {
+    let mut data = VectorData {
+      view_box: DEFAULT_VIEW_BOX,
+      stroke_width: DEFAULT_STROKE_WIDTH,
+      elements: Cow::Owned(Vec::new()),
+    };
+    let mut elements = Vec::new();
+
+    for tag in tags(markup)? {
+      let attr = |name: &str| attribute(tag, name);
+      let number = |name: &str| -> Result<f32, PathDataError> {
+        attr(name).map_or(Ok(0.0), |value| parse_number(value, name))
+      };
+
+      let commands = match tag_name(tag) {
+        "svg" => {
+          if let Some(view_box) = attr("viewBox") {
+            let values = numbers(view_box)?;
+            data.view_box = values
+              .try_into()
+              .map_err(|_| PathDataError(format!("invalid viewBox `{}`", view_box)))?;
+          }
+          if let Some(stroke_width) = attr("stroke-width") {
+            data.stroke_width = parse_number(stroke_width, "stroke-width")?;
+          }
+          continue;
+        }
+        "path" => parse_path(attr("d").unwrap_or_default())?,
+        "circle" => {
+          let r = number("r")?;
+          ellipse(number("cx")?, number("cy")?, r, r)
+        }
+        "ellipse" => ellipse(number("cx")?, number("cy")?, number("rx")?, number("ry")?),
+        "rect" => {
+          let (rx, ry) = match (attr("rx"), attr("ry")) {
+            (None, None) => (0.0, 0.0),
+            (Some(_), None) => (number("rx")?, number("rx")?),
+            (None, Some(_)) => (number("ry")?, number("ry")?),
+            (Some(_), Some(_)) => (number("rx")?, number("ry")?),
+          };
+          rect(
+            number("x")?,
+            number("y")?,
+            number("width")?,
+            number("height")?,
+            rx,
+            ry,
+          )
+        }
+        "line" => vec![
+          PathCommand::MoveTo([number("x1")?, number("y1")?]),
+          PathCommand::LineTo([number("x2")?, number("y2")?]),
+        ],
+        name @ ("polyline" | "polygon") => {
+          let mut commands = polyline(&numbers(attr("points").unwrap_or_default())?);
+          if name == "polygon" && !commands.is_empty() {
+            commands.push(PathCommand::Close);
+          }
+          commands
+        }
+        _ => continue,
+      };
+
+      let filled = attr("fill").is_some_and(|fill| fill != "none");
+      elements.push(VectorElement {
+        filled,
+        commands: Cow::Owned(commands),
+      });
+    }
+
+    data.elements = Cow::Owned(elements);
+    Ok(data)
+  }
+}
+
+/// Reads the viewBox of the root `<svg>` element without parsing the geometry.
+///
+/// Falls back to `0 0 width height` when only `width` and `height` are given. Returns
+/// `None` when neither is present or the values are invalid.
+pub fn parse_view_box(markup: &str) -> Option<[f32; 4]> {
+  let tag = tags(markup)
+    .ok()?
+    .into_iter()
+    .find(|tag| tag_name(tag) == "svg")?;
+  if let Some(view_box) = attribute(tag, "viewBox") {
+    return numbers(view_box).ok()?.try_into().ok();
+  }
+  let width = parse_number(attribute(tag, "width")?, "width").ok()?;
+  let height = parse_number(attribute(tag, "height")?, "height").ok()?;
+  Some([0.0, 0.0, width, height])
+}
+
+/// Number of straight segments used to measure a cubic curve.
+const CURVE_SAMPLES: usize = 16;
+
+impl VectorElement {
+  /// Returns the approximate length of the outline, in viewBox units.
+  pub fn length(&self) -> f32 {
+    segments(&self.commands)
+      .map(|segment| segment.length())
+      .sum()
+  }
+
+  /// Returns commands drawing the first `progress` fraction (0 to 1) of the outline,
+  /// as used by stroke "draw-on" animations.
+  pub fn trimmed(&self, progress: f32) -> Vec<PathCommand> {
+    if progress >= 1.0 {
+      return self.commands.to_vec();
+    }
+    if progress <= 0.0 {
+      return Vec::new();
+    }
+
+    let mut remaining = self.length() * progress;
+    let mut commands = Vec::new();
+
+    for segment in segments(&self.commands) {
+      if let Segment::Move(to) = segment {
+        commands.push(PathCommand::MoveTo(to));
+        continue;
+      }
+
+      let length = segment.length();
+      if remaining >= length {
+        commands.push(segment.command());
+        remaining -= length;
+      } else {
+        if remaining > 0.0 {
+          commands.push(segment.split(remaining / length));
+        }
+        break;
+      }
+    }
+
+    commands
+  }
+}
+
+/// A drawing command resolved against the current point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Segment {
+  Move(VectorPoint),
+  Line(VectorPoint, VectorPoint),
+  Cubic([VectorPoint; 4]),
+  Close(VectorPoint, VectorPoint),
+}
+
+fn segments(commands: &[PathCommand]) -> impl Iterator<Item = Segment> + '_ {
+  let mut current = [0.0, 0.0];
+  let mut start = [0.0, 0.0];
+
+  commands.iter().map(move |command| match *command {
+    PathCommand::MoveTo(to) => {
+      current = to;
+      start = to;
+      Segment::Move(to)
+    }
+    PathCommand::LineTo(to) => {
+      let from = std::mem::replace(&mut current, to);
+      Segment::Line(from, to)
+    }
+    PathCommand::CubicTo { ctrl1, ctrl2, to } => {
+      let from = std::mem::replace(&mut current, to);
+      Segment::Cubic([from, ctrl1, ctrl2, to])
+    }
+    PathCommand::Close => {
+      let from = std::mem::replace(&mut current, start);
+      Segment::Close(from, start)
+    }
+  })
+}
+
+fn distance(a: VectorPoint, b: VectorPoint) -> f32 {
+  (b[0] - a[0]).hypot(b[1] - a[1])
+}
+
+fn lerp(a: VectorPoint, b: VectorPoint, t: f32) -> VectorPoint {
+  [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+}
+
+fn cubic_point(points: &[VectorPoint; 4], t: f32) -> VectorPoint {
+  let ab = lerp(points[0], points[1], t);
+  let bc = lerp(points[1], points[2], t);
+  let cd = lerp(points[2], points[3], t);
+  lerp(lerp(ab, bc, t), lerp(bc, cd, t), t)
+}
+
+impl Segment {
+  fn length(&self) -> f32 {
+    match self {
+      Segment::Move(_) => 0.0,
+      Segment::Line(from, to) | Segment::Close(from, to) => distance(*from, *to),
+      Segment::Cubic(points) => (1..=CURVE_SAMPLES)
+        .map(|i| {
+          let t0 = (i - 1) as f32 / CURVE_SAMPLES as f32;
+          let t1 = i as f32 / CURVE_SAMPLES as f32;
+          distance(cubic_point(points, t0), cubic_point(points, t1))
+        })
+        .sum(),
+    }
+  }
+
+  fn command(&self) -> PathCommand {
+    match *self {
+      Segment::Move(to) => PathCommand::MoveTo(to),
+      Segment::Line(_, to) => PathCommand::LineTo(to),
+      Segment::Cubic([_, ctrl1, ctrl2, to]) => PathCommand::CubicTo { ctrl1, ctrl2, to },
+      Segment::Close(..) => PathCommand::Close,
+    }
+  }
+
+  /// Returns the command drawing the segment up to `fraction` of its length.
+  fn split(&self, fraction: f32) -> PathCommand {
+    match *self {
+      Segment::Move(to) => PathCommand::MoveTo(to),
+      Segment::Line(from, to) | Segment::Close(from, to) => {
+        PathCommand::LineTo(lerp(from, to, fraction))
+      }
+      Segment::Cubic(points) => {
+        // Map the length fraction to a curve parameter using the sampled lengths,
+        // then keep the first half of a de Casteljau subdivision.
+        let target = self.length() * fraction;
+        let mut travelled = 0.0;
+        let mut t = 1.0;
+        for i in 1..=CURVE_SAMPLES {
+          let t0 = (i - 1) as f32 / CURVE_SAMPLES as f32;
+          let t1 = i as f32 / CURVE_SAMPLES as f32;
+          let step = distance(cubic_point(&points, t0), cubic_point(&points, t1));
+          if travelled + step >= target {
+            t = t0 + (t1 - t0) * ((target - travelled) / step.max(f32::EPSILON));
+            break;
+          }
+          travelled += step;
+        }
+
+        let [p0, p1, p2, p3] = points;
+        let ab = lerp(p0, p1, t);
+        let bc = lerp(p1, p2, t);
+        let cd = lerp(p2, p3, t);
+        let abc = lerp(ab, bc, t);
+        let bcd = lerp(bc, cd, t);
+        PathCommand::CubicTo {
+          ctrl1: ab,
+          ctrl2: abc,
+          to: lerp(abc, bcd, t),
+        }
+      }
+    }
+  }
+}
+
+/// Returns the content of every start tag in the markup, skipping comments,
+/// declarations and closing tags.
+fn tags(markup: &str) -> Result<Vec<&str>, PathDataError> {
+  let mut tags = Vec::new();
+  let mut rest = markup;
+
+  while let Some(start) = rest.find('<') {
+    rest = &rest[start + 1..];
+
+    if let Some(comment) = rest.strip_prefix("!--") {
+      let end = comment
+        .find("-->")
+        .ok_or_else(|| PathDataError("unterminated comment".into()))?;
+      rest = &comment[end + 3..];
+      continue;
+    }
+
+    let end = rest
+      .find('>')
+      .ok_or_else(|| PathDataError("unterminated tag".into()))?;
+    let tag = &rest[..end];
+    rest = &rest[end + 1..];
+
+    if !tag.starts_with(['/', '?', '!']) {
+      tags.push(tag.trim_end_matches('/').trim());
+    }
+  }
+
+  Ok(tags)
+}
+
+fn tag_name(tag: &str) -> &str {
+  tag.split(char::is_whitespace).next().unwrap_or_default()
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+  let needle = format!("{}=", name);
+  tag.match_indices(&needle).find_map(|(index, _)| {
+    let preceded_by_space = tag[..index]
+      .chars()
+      .next_back()
+      .is_some_and(char::is_whitespace);
+    if !preceded_by_space {
+      return None;
+    }
+    let value = &tag[index + needle.len()..];
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let end = value[1..].find(quote)?;
+    Some(&value[1..=end])
+  })
+}
+
+fn parse_number(value: &str, name: &str) -> Result<f32, PathDataError> {
+  value
+    .trim()
+    .trim_end_matches("px")
+    .parse()
+    .map_err(|_| PathDataError(format!("invalid number `{}` for `{}`", value, name)))
+}
+
+fn numbers(value: &str) -> Result<Vec<f32>, PathDataError> {
+  let mut lexer = Lexer::new(value);
+  let mut numbers = Vec::new();
+  while lexer.has_number() {
+    numbers.push(lexer.number()?);
+  }
+  Ok(numbers)
+}
+
+fn polyline(points: &[f32]) -> Vec<PathCommand> {
+  points
+    .chunks_exact(2)
+    .enumerate()
+    .map(|(index, point)| {
+      let point = [point[0], point[1]];
+      if index == 0 {
+        PathCommand::MoveTo(point)
+      } else {
+        PathCommand::LineTo(point)
+      }
+    })
+    .collect()
+}
+
+fn ellipse(cx: f32, cy: f32, rx: f32, ry: f32) -> Vec<PathCommand> {
+  if rx <= 0.0 || ry <= 0.0 {
+    return Vec::new();
+  }
+
+  let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+  vec![
+    PathCommand::MoveTo([cx + rx, cy]),
+    PathCommand::CubicTo {
+      ctrl1: [cx + rx, cy + ky],
+      ctrl2: [cx + kx, cy + ry],
+      to: [cx, cy + ry],
+    },
+    PathCommand::CubicTo {
+      ctrl1: [cx - kx, cy + ry],
+      ctrl2: [cx - rx, cy + ky],
+      to: [cx - rx, cy],
+    },
+    PathCommand::CubicTo {
+      ctrl1: [cx - rx, cy - ky],
+      ctrl2: [cx - kx, cy - ry],
+      to: [cx, cy - ry],
+    },
+    PathCommand::CubicTo {
+      ctrl1: [cx + kx, cy - ry],
+      ctrl2: [cx + rx, cy - ky],
+      to: [cx + rx, cy],
+    },
+    PathCommand::Close,
+  ]
+}
+
+fn rect(x: f32, y: f32, width: f32, height: f32, rx: f32, ry: f32) -> Vec<PathCommand> {
+  if width <= 0.0 || height <= 0.0 {
+    return Vec::new();
+  }
+
+  let rx = rx.clamp(0.0, width / 2.0);
+  let ry = ry.clamp(0.0, height / 2.0);
+  let (right, bottom) = (x + width, y + height);
+
+  if rx == 0.0 || ry == 0.0 {
+    return vec![
+      PathCommand::MoveTo([x, y]),
+      PathCommand::LineTo([right, y]),
+      PathCommand::LineTo([right, bottom]),
+      PathCommand::LineTo([x, bottom]),
+      PathCommand::Close,
+    ];
+  }
+
+  let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+  vec![
+    PathCommand::MoveTo([x + rx, y]),
+    PathCommand::LineTo([right - rx, y]),
+    PathCommand::CubicTo {
+      ctrl1: [right - rx + kx, y],
+      ctrl2: [right, y + ry - ky],
+      to: [right, y + ry],
+    },
+    PathCommand::LineTo([right, bottom - ry]),
+    PathCommand::CubicTo {
+      ctrl1: [right, bottom - ry + ky],
+      ctrl2: [right - rx + kx, bottom],
+      to: [right - rx, bottom],
+    },
+    PathCommand::LineTo([x + rx, bottom]),
+    PathCommand::CubicTo {
+      ctrl1: [x + rx - kx, bottom],
+      ctrl2: [x, bottom - ry + ky],
+      to: [x, bottom - ry],
+    },
+    PathCommand::LineTo([x, y + ry]),
+    PathCommand::CubicTo {
+      ctrl1: [x, y + ry - ky],
+      ctrl2: [x + rx - kx, y],
+      to: [x + rx, y],
+    },
+    PathCommand::Close,
+  ]
+}
+
+/// Tokenizer for path data and point lists.
+struct Lexer<'a> {
+  input: &'a [u8],
+  position: usize,
+}
+
+impl<'a> Lexer<'a> {
+  fn new(input: &'a str) -> Self {
+    Self {
+      input: input.as_bytes(),
+      position: 0,
+    }
+  }
+
+  fn skip_separators(&mut self) {
+    while self
+      .input
+      .get(self.position)
+      .is_some_and(|c| c.is_ascii_whitespace() || *c == b',')
+    {
+      self.position += 1;
+    }
+  }
+
+  fn peek(&mut self) -> Option<u8> {
+    self.skip_separators();
+    self.input.get(self.position).copied()
+  }
+
+  fn has_number(&mut self) -> bool {
+    self
+      .peek()
+      .is_some_and(|c| c.is_ascii_digit() || matches!(c, b'-' | b'+' | b'.'))
+  }
+
+  fn command(&mut self) -> Option<u8> {
+    let c = self.peek().filter(u8::is_ascii_alphabetic)?;
+    self.position += 1;
+    Some(c)
+  }
+
+  fn number(&mut self) -> Result<f32, PathDataError> {
+    self.skip_separators();
+    let start = self.position;
+    let digits = |lexer: &mut Self| {
+      while lexer
+        .input
+        .get(lexer.position)
+        .is_some_and(u8::is_ascii_digit)
+      {
+        lexer.position += 1;
+      }
+    };
+
+    if matches!(self.input.get(self.position), Some(b'-' | b'+')) {
+      self.position += 1;
+    }
+    digits(self);
+    if self.input.get(self.position) == Some(&b'.') {
+      self.position += 1;
+      digits(self);
+    }
+    if matches!(self.input.get(self.position), Some(b'e' | b'E')) {
+      self.position += 1;
+      if matches!(self.input.get(self.position), Some(b'-' | b'+')) {
+        self.position += 1;
+      }
+      digits(self);
+    }
+
+    let text = std::str::from_utf8(&self.input[start..self.position]).unwrap_or_default();
+    text
+      .parse()
+      .map_err(|_| PathDataError(format!("expected a number at offset {}", start)))
+  }
+
+  /// Reads an arc flag, which may be written without a separator (`a1 1 0 011 1`).
+  fn flag(&mut self) -> Result<bool, PathDataError> {
+    match self.peek() {
+      Some(b'0') => {
+        self.position += 1;
+        Ok(false)
+      }
+      Some(b'1') => {
+        self.position += 1;
+        Ok(true)
+      }
+      _ => Err(PathDataError(format!(
+        "expected an arc flag at offset {}",
+        self.position
+      ))),
+    }
+  }
+
+  fn point(&mut self) -> Result<VectorPoint, PathDataError> {
+    Ok([self.number()?, self.number()?])
+  }
+}
+
+fn add(a: VectorPoint, b: VectorPoint) -> VectorPoint {
+  [a[0] + b[0], a[1] + b[1]]
+}
+
+fn reflect(point: VectorPoint, center: VectorPoint) -> VectorPoint {
+  [2.0 * center[0] - point[0], 2.0 * center[1] - point[1]]
+}
+
+/// Parses the `d` attribute of a `<path>` element.
+fn parse_path(d: &str) -> Result<Vec<PathCommand>, PathDataError> {
+  let mut lexer = Lexer::new(d);
+  let mut commands = Vec::new();
+  let mut current = [0.0, 0.0];
+  let mut subpath_start = [0.0, 0.0];
+  // Last control points, used to reflect smooth curves (`S` and `T`).
+  let mut last_cubic_ctrl: Option<VectorPoint> = None;
+  let mut last_quad_ctrl: Option<VectorPoint> = None;
+  let mut command = None;
+
+  loop {
+    command = match lexer.command() {
+      Some(next) => Some(next),
+      None if lexer.has_number() => match command {
+        // Extra coordinates after a move are implicit line commands.
+        Some(b'M') => Some(b'L'),
+        Some(b'm') => Some(b'l'),
+        Some(b'Z' | b'z') | None => {
+          return Err(PathDataError("path data must start with a command".into()));
+        }
+        previous => previous,
+      },
+      None if lexer.peek().is_none() => break,
+      None => {
+        return Err(PathDataError(format!(
+          "unexpected character in path data `{}`",
+          d
+        )));
+      }
+    };
+
+    let Some(c) = command else { break };
+    let relative = c.is_ascii_lowercase();
+    let offset = if relative { current } else { [0.0, 0.0] };
+    let mut cubic_ctrl = None;
+    let mut quad_ctrl = None;
+
+    match c.to_ascii_uppercase() {
+      b'M' => {
+        current = add(offset, lexer.point()?);
+        subpath_start = current;
+        commands.push(PathCommand::MoveTo(current));
+      }
+      b'L' => {
+        current = add(offset, lexer.point()?);
+        commands.push(PathCommand::LineTo(current));
+      }
+      b'H' => {
+        current[0] = offset[0] + lexer.number()?;
+        commands.push(PathCommand::LineTo(current));
+      }
+      b'V' => {
+        current[1] = offset[1] + lexer.number()?;
+        commands.push(PathCommand::LineTo(current));
+      }
+      b'C' | b'S' => {
+        let ctrl1 = if c.eq_ignore_ascii_case(&b'C') {
+          add(offset, lexer.point()?)
+        } else {
+          last_cubic_ctrl.map_or(current, |ctrl| reflect(ctrl, current))
+        };
+        let ctrl2 = add(offset, lexer.point()?);
+        let to = add(offset, lexer.point()?);
+        commands.push(PathCommand::CubicTo { ctrl1, ctrl2, to });
+        cubic_ctrl = Some(ctrl2);
+        current = to;
+      }
+      b'Q' | b'T' => {
+        let ctrl = if c.eq_ignore_ascii_case(&b'Q') {
+          add(offset, lexer.point()?)
+        } else {
+          last_quad_ctrl.map_or(current, |ctrl| reflect(ctrl, current))
+        };
+        let to = add(offset, lexer.point()?);
+        commands.push(quad_to_cubic(current, ctrl, to));
+        quad_ctrl = Some(ctrl);
+        current = to;
+      }
+      b'A' => {
+        let rx = lexer.number()?;
+        let ry = lexer.number()?;
+        let x_rotation = lexer.number()?;
+        let large_arc = lexer.flag()?;
+        let sweep = lexer.flag()?;
+        let to = add(offset, lexer.point()?);
+        arc_to_cubics(
+          current,
+          [rx, ry],
+          x_rotation,
+          large_arc,
+          sweep,
+          to,
+          &mut commands,
+        );
+        current = to;
+      }
+      b'Z' => {
+        commands.push(PathCommand::Close);
+        current = subpath_start;
+      }
+      _ => {
+        return Err(PathDataError(format!(
+          "unsupported path command `{}`",
+          c as char
+        )));
+      }
+    }
+
+    last_cubic_ctrl = cubic_ctrl;
+    last_quad_ctrl = quad_ctrl;
+  }
+
+  Ok(commands)
+}
+
+fn quad_to_cubic(from: VectorPoint, ctrl: VectorPoint, to: VectorPoint) -> PathCommand {
+  let lerp = |a: VectorPoint, b: VectorPoint| {
+    [
+      a[0] + (b[0] - a[0]) * 2.0 / 3.0,
+      a[1] + (b[1] - a[1]) * 2.0 / 3.0,
+    ]
+  };
+  PathCommand::CubicTo {
+    ctrl1: lerp(from, ctrl),
+    ctrl2: lerp(to, ctrl),
+    to,
+  }
+}
+
+/// Converts an SVG elliptical arc into cubic curves of at most a quarter turn each,
+/// following the endpoint to center conversion of the SVG specification.
+fn arc_to_cubics(
+  from: VectorPoint,
+  radii: VectorPoint,
+  x_rotation_degrees: f32,
+  large_arc: bool,
+  sweep: bool,
+  to: VectorPoint,
+  commands: &mut Vec<PathCommand>,
+) {
+  if from == to {
+    return;
+  }
+
+  let (mut rx, mut ry) = (radii[0].abs(), radii[1].abs());
+  if rx == 0.0 || ry == 0.0 {
+    commands.push(PathCommand::LineTo(to));
+    return;
+  }
+
+  let (sin_phi, cos_phi) = x_rotation_degrees.to_radians().sin_cos();
+  let dx = (from[0] - to[0]) / 2.0;
+  let dy = (from[1] - to[1]) / 2.0;
+  let x1 = cos_phi * dx + sin_phi * dy;
+  let y1 = -sin_phi * dx + cos_phi * dy;
+
+  // Scale the radii up when they are too small to reach the end point.
+  let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+  if lambda > 1.0 {
+    rx *= lambda.sqrt();
+    ry *= lambda.sqrt();
+  }
+
+  let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+  let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+  let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+  let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+  let cx1 = coefficient * rx * y1 / ry;
+  let cy1 = -coefficient * ry * x1 / rx;
+
+  let cx = cos_phi * cx1 - sin_phi * cy1 + (from[0] + to[0]) / 2.0;
+  let cy = sin_phi * cx1 + cos_phi * cy1 + (from[1] + to[1]) / 2.0;
+
+  let angle = |ux: f32, uy: f32, vx: f32, vy: f32| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+  let start_angle = angle(1.0, 0.0, (x1 - cx1) / rx, (y1 - cy1) / ry);
+  let mut sweep_angle = angle(
+    (x1 - cx1) / rx,
+    (y1 - cy1) / ry,
+    (-x1 - cx1) / rx,
+    (-y1 - cy1) / ry,
+  );
+  if !sweep && sweep_angle > 0.0 {
+    sweep_angle -= TAU;
+  } else if sweep && sweep_angle < 0.0 {
+    sweep_angle += TAU;
+  }
+
+  let segments = (sweep_angle.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+  let delta = sweep_angle / segments as f32;
+  let t = 4.0 / 3.0 * (delta / 4.0).tan();
+  let map = |x: f32, y: f32| {
+    [
+      cx + rx * cos_phi * x - ry * sin_phi * y,
+      cy + rx * sin_phi * x + ry * cos_phi * y,
+    ]
+  };
+
+  for segment in 0..segments {
+    let a = start_angle + delta * segment as f32;
+    let b = a + delta;
+    let (sin_a, cos_a) = a.sin_cos();
+    let (sin_b, cos_b) = b.sin_cos();
+    let end = if segment + 1 == segments {
+      to
+    } else {
+      map(cos_b, sin_b)
+    };
+
+    commands.push(PathCommand::CubicTo {
+      ctrl1: map(cos_a - t * sin_a, sin_a + t * cos_a),
+      ctrl2: map(cos_b + t * sin_b, sin_b - t * cos_b),
+      to: end,
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn assert_close(a: VectorPoint, b: VectorPoint) {
+    assert!(
+      (a[0] - b[0]).abs() < 1e-3 && (a[1] - b[1]).abs() < 1e-3,
+      "{:?} != {:?}",
+      a,
+      b
+    );
+  }
+
+  fn commands(markup: &str) -> Vec<PathCommand> {
+    let data = VectorData::parse(markup).unwrap();
+    data.elements[0].commands.to_vec()
+  }
+
+  #[test]
+  fn test_parse_reads_view_box_and_stroke_width() {
+    let data =
+      VectorData::parse(r#"<svg viewBox="0 0 32 16" stroke-width="1.5"><path d="M0 0h1"/></svg>"#)
+        .unwrap();
+    assert_eq!(data.view_box, [0.0, 0.0, 32.0, 16.0]);
+    assert_eq!(data.stroke_width, 1.5);
+    assert_eq!(data.elements.len(), 1);
+  }
+
+  #[test]
+  fn test_parse_view_box() {
+    assert_eq!(
+      parse_view_box(r#"<?xml version="1.0"?><svg viewBox="0 0 64 16"><path d="M0 0"/></svg>"#),
+      Some([0.0, 0.0, 64.0, 16.0])
+    );
+    assert_eq!(
+      parse_view_box(r#"<svg width="120px" height="40"></svg>"#),
+      Some([0.0, 0.0, 120.0, 40.0])
+    );
+    assert_eq!(parse_view_box(r#"<svg viewBox="0 0 24"></svg>"#), None);
+    assert_eq!(parse_view_box("<svg></svg>"), None);
+    assert_eq!(parse_view_box("not svg"), None);
+  }
+
+  #[test]
+  fn test_parse_path_relative_and_implicit_commands() {
+    let commands = commands(r#"<svg><path d="m19 12-7 7-7-7" /></svg>"#);
+    assert_eq!(
+      commands,
+      vec![
+        PathCommand::MoveTo([19.0, 12.0]),
+        PathCommand::LineTo([12.0, 19.0]),
+        PathCommand::LineTo([5.0, 12.0]),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_parse_path_horizontal_vertical_and_close() {
+    let commands = commands(r#"<svg><path d="M2 2h4v4H2z" /></svg>"#);
+    assert_eq!(
+      commands,
+      vec![
+        PathCommand::MoveTo([2.0, 2.0]),
+        PathCommand::LineTo([6.0, 2.0]),
+        PathCommand::LineTo([6.0, 6.0]),
+        PathCommand::LineTo([2.0, 6.0]),
+        PathCommand::Close,
+      ]
+    );
+  }
+
+  #[test]
+  fn test_parse_path_compact_numbers() {
+    let commands = commands(r#"<svg><path d="M.5.5l-1e1.25" /></svg>"#);
+    assert_eq!(
+      commands,
+      vec![
+        PathCommand::MoveTo([0.5, 0.5]),
+        PathCommand::LineTo([-9.5, 0.75]),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_parse_path_smooth_cubic_reflects_control_point() {
+    let commands = commands(r#"<svg><path d="M0 0C0 1 1 2 2 2S4 3 4 4" /></svg>"#);
+    let PathCommand::CubicTo { ctrl1, .. } = commands[2] else {
+      panic!("expected a cubic curve");
+    };
+    assert_close(ctrl1, [3.0, 2.0]);
+  }
+
+  #[test]
+  fn test_parse_path_arc_with_compact_flags_ends_at_target() {
+    let commands = commands(r#"<svg><path d="M10 12a2 2 0 014 0" /></svg>"#);
+    let Some(PathCommand::CubicTo { to, .. }) = commands.last() else {
+      panic!("expected the arc to become cubic curves");
+    };
+    assert_close(*to, [14.0, 12.0]);
+  }
+
+  #[test]
+  fn test_parse_path_semicircle_passes_through_apex() {
+    // Sweeping clockwise from left to right on screen goes over the top of the circle.
+    let commands = commands(r#"<svg><path d="M10 12a2 2 0 0 1 4 0" /></svg>"#);
+    assert_eq!(commands.len(), 3);
+    let PathCommand::CubicTo { to, .. } = commands[1] else {
+      panic!("expected a cubic curve");
+    };
+    assert_close(to, [12.0, 10.0]);
+  }
+
+  #[test]
+  fn test_parse_circle() {
+    let commands = commands(r#"<svg><circle cx="12" cy="12" r="10" /></svg>"#);
+    assert_eq!(commands.first(), Some(&PathCommand::MoveTo([22.0, 12.0])));
+    assert_eq!(commands.len(), 6);
+  }
+
+  #[test]
+  fn test_parse_rect_with_rounded_corners() {
+    let commands = commands(r#"<svg><rect width="20" height="12" x="2" y="6" rx="2" /></svg>"#);
+    assert_eq!(commands.first(), Some(&PathCommand::MoveTo([4.0, 6.0])));
+    assert_eq!(commands.len(), 10);
+  }
+
+  #[test]
+  fn test_parse_line_and_polygon() {
+    let data = VectorData::parse(
+      r#"<svg><line x1="1" y1="2" x2="3" y2="4" /><polygon points="0,0 4,0 2,3" /></svg>"#,
+    )
+    .unwrap();
+    assert_eq!(
+      data.elements[0].commands.as_ref(),
+      &[
+        PathCommand::MoveTo([1.0, 2.0]),
+        PathCommand::LineTo([3.0, 4.0])
+      ]
+    );
+    assert_eq!(data.elements[1].commands.last(), Some(&PathCommand::Close));
+  }
+
+  #[test]
+  fn test_parse_marks_filled_elements() {
+    let data = VectorData::parse(
+      r#"<svg fill="none"><circle cx="1" cy="1" r="1" fill="currentColor" /><path d="M0 0h1" /></svg>"#,
+    )
+    .unwrap();
+    assert!(data.elements[0].filled);
+    assert!(!data.elements[1].filled);
+  }
+
+  #[test]
+  fn test_length_of_lines_and_close() {
+    let data = VectorData::parse(r#"<svg><path d="M0 0h4v3z" /></svg>"#).unwrap();
+    assert!((data.elements[0].length() - 12.0).abs() < 1e-4);
+  }
+
+  #[test]
+  fn test_length_of_circle() {
+    let data = VectorData::parse(r#"<svg><circle cx="12" cy="12" r="10" /></svg>"#).unwrap();
+    let circumference = 2.0 * std::f32::consts::PI * 10.0;
+    assert!((data.elements[0].length() - circumference).abs() < 0.1);
+  }
+
+  #[test]
+  fn test_trimmed_bounds() {
+    let data = VectorData::parse(r#"<svg><path d="M0 0h4v3z" /></svg>"#).unwrap();
+    let element = &data.elements[0];
+
+    assert!(element.trimmed(0.0).is_empty());
+    assert_eq!(element.trimmed(1.0), element.commands.to_vec());
+  }
+
+  #[test]
+  fn test_trimmed_splits_line() {
+    let data = VectorData::parse(r#"<svg><path d="M0 0h4v3z" /></svg>"#).unwrap();
+    assert_eq!(
+      data.elements[0].trimmed(0.5),
+      vec![
+        PathCommand::MoveTo([0.0, 0.0]),
+        PathCommand::LineTo([4.0, 0.0]),
+        PathCommand::LineTo([4.0, 2.0]),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_trimmed_splits_curve() {
+    let data = VectorData::parse(r#"<svg><circle cx="0" cy="0" r="1" /></svg>"#).unwrap();
+    let commands = data.elements[0].trimmed(0.125);
+
+    assert_eq!(commands.len(), 2);
+    let PathCommand::CubicTo { to, .. } = commands[1] else {
+      panic!("expected a cubic curve");
+    };
+    let diagonal = std::f32::consts::FRAC_1_SQRT_2;
+    assert!((to[0] - diagonal).abs() < 0.01 && (to[1] - diagonal).abs() < 0.01);
+  }
+
+  #[test]
+  fn test_parse_rejects_invalid_path() {
+    assert!(VectorData::parse(r#"<svg><path d="M0 0 X 1" /></svg>"#).is_err());
+    assert!(VectorData::parse(r#"<svg><path d="0 0" /></svg>"#).is_err());
+  }
+}
@@ -6,45 +6,19 @@ use gpui::{
   App, AppContext, Application, AssetSource, Bounds, Context, Entity, FocusHandle, Focusable, Hsla,
   InteractiveElement, IntoElement, KeyBinding, MouseButton, Render, SharedString,
   StatefulInteractiveElement, Styled, Subscription, Window, WindowBounds, WindowOptions, actions,
-  div, prelude::*, px, radians, rgb, uniform_list,
+  div, prelude::*, px, radians, rgb,
+};
+use gpui_lucide::search::Query;
+use gpui_lucide::{
+  CONTRAST_GRAPHICS, CONTRAST_TEXT, Icon, IconLabel, IconName, IconPicker, IconPickerEvent,
+  IconPickerStyle, IconSize, IconTransform, IconTransition, TextInput, TextInputEvent,
+  TransitionStyle, bind_icon_picker_keys, bind_text_input_keys, contrast_ratio, contrasting_color,
 };
-use gpui_lucide::{Icon, IconName, IconSize};
 use std::borrow::Cow;
 use std::fs;
 use std::path::PathBuf;
 
-mod search_input;
-use search_input::SearchInput;
-
-actions!(
-  playground,
-  [
-    Quit,
-    ClearSearch,
-    Backspace,
-    Delete,
-    Left,
-    Right,
-    SelectLeft,
-    SelectRight,
-    SelectAll,
-    Home,
-    End,
-    MoveWordLeft,
-    MoveWordRight,
-    SelectWordLeft,
-    SelectWordRight,
-    SelectToBeginningOfLine,
-    SelectToEndOfLine,
-    DeleteWordBackward,
-    DeleteToBeginningOfLine,
-    DeleteToEndOfLine,
-    ShowCharacterPalette,
-    Paste,
-    Cut,
-    Copy
-  ]
-);
+actions!(playground, [Quit]);
 
 struct Assets {
   base: PathBuf,
@@ -144,27 +118,16 @@ const COLOR_PRESETS: &[(u32, &str)] = &[
 
 struct Playground {
   focus_handle: FocusHandle,
-  search_input: Entity<SearchInput>,
-  _search_subscription: Subscription,
+  search_input: Entity<TextInput>,
+  icon_picker: Entity<IconPicker>,
+  _subscriptions: Vec<Subscription>,
   is_dark: bool,
   selected_color: u32,
   selected_size: IconSize,
   rotation_degrees: f32,
-  filtered_icons: Vec<IconName>,
   hovered_icon: Option<IconName>,
 }
 
-fn filter_icons(query: &str) -> Vec<IconName> {
-  let query = query.to_lowercase();
-  if query.is_empty() {
-    IconName::all().collect()
-  } else {
-    IconName::all()
-      .filter(|icon| icon.name().contains(&query))
-      .collect()
-  }
-}
-
 impl Playground {
   fn icon_render_color(&self) -> gpui::Rgba {
     if self.is_dark {
@@ -176,47 +139,107 @@ impl Playground {
 
   fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
     let focus_handle = cx.focus_handle();
-    let search_input = cx.new(SearchInput::new);
+    let search_input = cx.new(|cx| {
+      let mut input = TextInput::new(cx).with_placeholder("Search, e.g. cat:arrows -circle");
+      input.set_validator(
+        |text| {
+          Query::parse(text)
+            .map(|_| ())
+            .map_err(|error| error.to_string().into())
+        },
+        cx,
+      );
+      input
+    });
     let search_focus = search_input.read(cx).focus_handle(cx);
     window.focus(&search_focus, cx);
 
-    let search_subscription = cx.observe(&search_input, |this, search_input, cx| {
-      this.filtered_icons = filter_icons(search_input.read(cx).text());
-      if let Some(hovered) = this.hovered_icon
-        && !this.filtered_icons.contains(&hovered)
-      {
-        this.hovered_icon = None;
-      }
-      cx.notify();
-    });
+    let icon_picker = cx.new(IconPicker::lucide);
+
+    let subscriptions = vec![
+      cx.subscribe(&search_input, |this, _, event, cx| {
+        let TextInputEvent::Changed(query) = event else {
+          return;
+        };
+        this
+          .icon_picker
+          .update(cx, |picker, cx| picker.set_query(query.clone(), cx));
+        if let Some(hovered) = this.hovered_icon
+          && !this.icon_picker.read(cx).matches().contains(&hovered)
+        {
+          this.hovered_icon = None;
+        }
+        cx.notify();
+      }),
+      // Enter in the search input moves focus to the grid, so the arrow keys and enter
+      // navigate and pick icons.
+      cx.subscribe_in(&search_input, window, |this, _, event, window, cx| {
+        if let TextInputEvent::Submitted(_) = event {
+          this
+            .icon_picker
+            .update(cx, |picker, cx| picker.focus(window, cx));
+        }
+      }),
+      cx.subscribe(&icon_picker, |this, _, event, cx| match event {
+        IconPickerEvent::Highlighted(icon) => this.set_hovered(*icon, cx),
+        IconPickerEvent::Selected(icon) => this.set_hovered(Some(*icon), cx),
+      }),
+    ];
 
     let mut app = Self {
       focus_handle,
       search_input,
-      _search_subscription: search_subscription,
+      icon_picker,
+      _subscriptions: subscriptions,
       is_dark: true,
       selected_color: 0xffffff,
       selected_size: IconSize::Large,
       rotation_degrees: 0.0,
-      filtered_icons: vec![],
       hovered_icon: None,
     };
-    app.filtered_icons = filter_icons("");
+    app.sync_picker(cx);
     app
   }
 
+  /// Pushes the sidebar settings to the icon picker.
+  fn sync_picker(&mut self, cx: &mut Context<Self>) {
+    let color = self.icon_render_color();
+    let size = self.selected_size;
+    let transform = IconTransform::new().rotate(radians(self.rotation_degrees.to_radians()));
+    let is_dark = self.is_dark;
+
+    self.icon_picker.update(cx, |picker, cx| {
+      picker.set_icon_color(color, cx);
+      picker.set_icon_size(size, cx);
+      picker.set_icon_transform(transform, cx);
+      picker.set_style(
+        IconPickerStyle {
+          cell: Some(theme::bg_secondary(is_dark)),
+          cell_hover: Some(theme::bg_hover(is_dark)),
+          cell_selected: Some(theme::border(is_dark)),
+          label: Some(theme::text_muted(is_dark)),
+          ..IconPickerStyle::default()
+        },
+        cx,
+      );
+    });
+  }
+
   fn set_color(&mut self, color: u32, cx: &mut Context<Self>) {
     self.selected_color = color;
+    self.sync_picker(cx);
     cx.notify();
   }
 
   fn set_size(&mut self, size: IconSize, cx: &mut Context<Self>) {
     self.selected_size = size;
+    self.sync_picker(cx);
     cx.notify();
   }
 
   fn set_rotation(&mut self, degrees: f32, cx: &mut Context<Self>) {
     self.rotation_degrees = degrees;
+    self.sync_picker(cx);
     cx.notify();
   }
 
@@ -227,6 +250,7 @@ impl Playground {
 
   fn toggle_theme(&mut self, cx: &mut Context<Self>) {
     self.is_dark = !self.is_dark;
+    self.sync_picker(cx);
     cx.notify();
   }
 }
@@ -239,8 +263,6 @@ impl Focusable for Playground {
 
 impl Render for Playground {
   fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-    let icon_color = self.icon_render_color();
-    let rotation_rad = self.rotation_degrees.to_radians();
     let is_dark = self.is_dark;
 
     let viewport_width: f32 = window.viewport_size().width.into();
@@ -262,7 +284,7 @@ impl Render for Playground {
           .flex()
           .overflow_hidden()
           .child(self.render_sidebar(cx))
-          .child(self.render_icon_grid(icon_color, rotation_rad, grid_width, cx)),
+          .child(self.render_icon_grid(grid_width, cx)),
       )
   }
 }
@@ -363,13 +385,17 @@ impl Playground {
         }),
       )
       .child(
-        Icon::new(if is_dark {
-          IconName::Sun
-        } else {
-          IconName::Moon
-        })
-        .color(theme::text(is_dark))
-        .with_size(IconSize::Medium),
+        IconTransition::new(
+          "theme-toggle-icon",
+          Icon::new(if is_dark {
+            IconName::Sun
+          } else {
+            IconName::Moon
+          })
+          .color(theme::text(is_dark))
+          .with_size(IconSize::Medium),
+        )
+        .style(TransitionStyle::RotateSwap),
       )
   }
 
@@ -516,6 +542,7 @@ impl Playground {
     let color = self.icon_render_color();
     let rotation = radians(self.rotation_degrees.to_radians());
     let is_dark = self.is_dark;
+    let contrast = contrast_ratio(color.into(), theme::bg(is_dark));
 
     div()
       .flex_1()
@@ -539,30 +566,36 @@ impl Playground {
           .text_color(theme::text_muted(is_dark))
           .child(icon.name()),
       )
+      .when(contrast < CONTRAST_GRAPHICS, |this| {
+        this.child(
+          IconLabel::new(
+            IconName::TriangleAlert,
+            format!("Low contrast {:.1}:1 against the background", contrast),
+          )
+          .color(contrasting_color(
+            Some(rgb(0xf59e0b).into()),
+            theme::bg(is_dark),
+            CONTRAST_TEXT,
+          ))
+          .text_xs(),
+        )
+      })
   }
 
-  fn render_icon_grid(
-    &mut self,
-    color: gpui::Rgba,
-    rotation: f32,
-    grid_width: f32,
-    cx: &mut Context<Self>,
-  ) -> impl IntoElement {
-    let count = self.filtered_icons.len();
-    let selected_size = self.selected_size;
-    let color_hsla: Hsla = color.into();
+  fn render_icon_grid(&mut self, grid_width: f32, cx: &mut Context<Self>) -> impl IntoElement {
     let is_dark = self.is_dark;
-
-    const CARD_SIZE: f32 = 72.0;
-    const GAP: f32 = 8.0;
+    let style = IconPickerStyle::default();
+    let card_size = f32::from(style.cell_size);
+    let gap = f32::from(style.gap);
     const PADDING: f32 = 16.0; // px_4 = 16px on each side
 
     let available_width = grid_width - (PADDING * 2.0);
-    let items_per_row = ((available_width + GAP) / (CARD_SIZE + GAP)).floor() as usize;
-    let items_per_row = items_per_row.max(1);
-
-    const ROW_HEIGHT: f32 = CARD_SIZE + GAP;
-    let num_rows = count.div_ceil(items_per_row);
+    let items_per_row = ((available_width + gap) / (card_size + gap)).floor() as usize;
+    self
+      .icon_picker
+      .update(cx, |picker, cx| picker.set_columns(items_per_row, cx));
+    let count = self.icon_picker.read(cx).matches().len();
+    let query_error = self.search_input.read(cx).error().cloned();
 
     div()
       .flex_1()
@@ -580,101 +613,15 @@ impl Playground {
           .border_b_1()
           .border_color(theme::border(is_dark))
           .child(div().flex_1().child(self.render_search_input(cx)))
-          .child(
-            div()
+          .child(match query_error {
+            Some(error) => div().text_sm().text_color(rgb(0xef4444)).child(error),
+            None => div()
               .text_sm()
               .text_color(theme::text_muted(is_dark))
               .child(format!("{} icons", count)),
-          ),
+          }),
       )
-      .child(
-        uniform_list(
-          "icon-grid",
-          num_rows,
-          cx.processor(
-            move |this, visible_range: std::ops::Range<usize>, _window, cx| {
-              visible_range
-                .map(|row_idx| {
-                  let start_idx = row_idx * items_per_row;
-                  let end_idx = (start_idx + items_per_row).min(this.filtered_icons.len());
-
-                  div()
-                    .id(row_idx)
-                    .h(px(ROW_HEIGHT))
-                    .px_4()
-                    .flex()
-                    .gap_2()
-                    .children((start_idx..end_idx).map(|idx| {
-                      let icon: IconName = this.filtered_icons[idx];
-                      let name = icon.name();
-                      let truncated_name = if name.len() > 10 {
-                        format!("{}...", &name[..8])
-                      } else {
-                        name.to_string()
-                      };
-
-                      div()
-                        .id(SharedString::from(name))
-                        .w(px(CARD_SIZE))
-                        .h(px(CARD_SIZE))
-                        .flex()
-                        .flex_col()
-                        .items_center()
-                        .justify_center()
-                        .gap_1()
-                        .rounded_lg()
-                        .cursor_pointer()
-                        .bg(theme::bg_secondary(is_dark))
-                        .hover(|s| s.bg(theme::bg_hover(is_dark)))
-                        .on_hover(cx.listener(move |this, is_hovered, _, cx| {
-                          if *is_hovered {
-                            this.set_hovered(Some(icon), cx);
-                          } else {
-                            this.set_hovered(None, cx);
-                          }
-                        }))
-                        .child(
-                          Icon::new(icon)
-                            .color(color_hsla)
-                            .with_size(selected_size)
-                            .rotate(radians(rotation)),
-                        )
-                        .child(
-                          div()
-                            .text_xs()
-                            .text_color(theme::text_muted(is_dark))
-                            .overflow_hidden()
-                            .max_w_full()
-                            .truncate()
-                            .child(truncated_name),
-                        )
-                    }))
-                })
-                .collect()
-            },
-          ),
-        )
-        .flex_1()
-        .pt_4(),
-      )
-  }
-}
-
-#[cfg(test)]
-mod tests {
-  use super::*;
-
-  #[test]
-  fn test_filter_icons_matches_query() {
-    let icons = filter_icons("heart");
-    assert!(icons.contains(&IconName::Heart));
-  }
-
-  #[test]
-  fn test_filter_icons_is_case_insensitive() {
-    let lower = filter_icons("heart");
-    let upper = filter_icons("HEART");
-    assert_eq!(lower, upper);
+      .child(div().flex_1().child(self.icon_picker.clone()))
   }
 }
 
@@ -689,43 +636,10 @@ fn main() {
         .to_path_buf(),
     })
     .run(|cx: &mut App| {
-      cx.bind_keys([
-        KeyBinding::new("cmd-q", Quit, None),
-        KeyBinding::new("escape", ClearSearch, Some("SearchInput")),
-        KeyBinding::new("backspace", Backspace, Some("SearchInput")),
-        KeyBinding::new("alt-backspace", DeleteWordBackward, Some("SearchInput")),
-        KeyBinding::new(
-          "cmd-backspace",
-          DeleteToBeginningOfLine,
-          Some("SearchInput"),
-        ),
-        KeyBinding::new("delete", Delete, Some("SearchInput")),
-        KeyBinding::new("cmd-delete", DeleteToEndOfLine, Some("SearchInput")),
-        KeyBinding::new("left", Left, Some("SearchInput")),
-        KeyBinding::new("right", Right, Some("SearchInput")),
-        KeyBinding::new("alt-left", MoveWordLeft, Some("SearchInput")),
-        KeyBinding::new("alt-right", MoveWordRight, Some("SearchInput")),
-        KeyBinding::new("cmd-left", Home, Some("SearchInput")),
-        KeyBinding::new("cmd-right", End, Some("SearchInput")),
-        KeyBinding::new("shift-left", SelectLeft, Some("SearchInput")),
-        KeyBinding::new("shift-right", SelectRight, Some("SearchInput")),
-        KeyBinding::new("shift-alt-left", SelectWordLeft, Some("SearchInput")),
-        KeyBinding::new("shift-alt-right", SelectWordRight, Some("SearchInput")),
-        KeyBinding::new(
-          "shift-cmd-left",
-          SelectToBeginningOfLine,
-          Some("SearchInput"),
-        ),
-        KeyBinding::new("shift-cmd-right", SelectToEndOfLine, Some("SearchInput")),
-        KeyBinding::new("cmd-a", SelectAll, Some("SearchInput")),
-        KeyBinding::new("cmd-v", Paste, Some("SearchInput")),
-        KeyBinding::new("cmd-c", Copy, Some("SearchInput")),
-        KeyBinding::new("cmd-x", Cut, Some("SearchInput")),
-        KeyBinding::new("home", Home, Some("SearchInput")),
-        KeyBinding::new("end", End, Some("SearchInput")),
-        KeyBinding::new("ctrl-cmd-space", ShowCharacterPalette, Some("SearchInput")),
-      ]);
+      cx.bind_keys([KeyBinding::new("cmd-q", Quit, None)]);
 
+      bind_text_input_keys(cx);
+      bind_icon_picker_keys(cx);
       cx.on_action(|_: &Quit, cx| cx.quit());
 
       let bounds = Bounds::centered(None, gpui::size(px(1200.0), px(800.0)), cx);
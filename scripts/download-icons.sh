@@ -24,7 +24,7 @@ trap cleanup EXIT
 
 echo -e "${BLUE}Step 1: Preparing icons directory${NC}"
 mkdir -p "$ICONS_DIR"
-rm -f "$ICONS_DIR"/*.svg 2>/dev/null || true
+rm -f "$ICONS_DIR"/*.svg "$ICONS_DIR"/*.json 2>/dev/null || true
 
 echo -e "${BLUE}Step 2: Cloning Lucide repository (sparse checkout)${NC}"
 echo -e "${GREEN}📥 This may take a moment...${NC}"
@@ -61,6 +61,12 @@ for svg_file in icons/*.svg; do
     printf "\r${GREEN}   [${BAR}${SPACE}] %3d%% (%d/%d)${NC}" "$PERCENT" "$CURRENT" "$TOTAL_ICONS"
     
     cp "$svg_file" "$ICONS_DIR/"
+
+    # Tags and categories used by the generated metadata
+    json_file="${svg_file%.svg}.json"
+    if [ -f "$json_file" ]; then
+        cp "$json_file" "$ICONS_DIR/"
+    fi
 done
 
 echo ""